@@ -10,6 +10,7 @@ def nest(var: str, n: int, prefix: str = '') -> str:
 
 print(
 f"""\
+#[allow(clippy::too_many_arguments, clippy::type_complexity, deprecated)]
 pub trait Productable: Iterator + Sized
 where Self::Item: Clone {{
     #[inline]
@@ -24,6 +25,7 @@ for n in range(3, 17):
     print(
 f"""\
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product{n}<{', '.join(f'I{i}' for i in range(2,n+1))}>(self, {', '.join(f'i{i}: I{i}' for i in range(2,n+1))}) -> impl Iterator<Item = (Self::Item, {', '.join(f'I{i}::Item' for i in range(2,n+1))})>
     where {', '.join(f'I{i}: Iterator' for i in range(2,n+1))},
           {', '.join(f'I{i}: Clone' for i in range(2,n+1))},
@@ -42,6 +44,8 @@ for n in range(3, 17):
     print(
 f"""\
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product{n}<{', '.join(f'I{i}' for i in range(1,n+1))}>({', '.join(f'i{i}: I{i}' for i in range(1,n+1))}) -> impl Iterator<Item = ({', '.join(f'I{i}::Item' for i in range(1,n+1))})>
 where {', '.join(f'I{i}: Iterator' for i in range(1,n+1))},
       {', '.join(f'I{i}: Clone' for i in range(2,n+1))},
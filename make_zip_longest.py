@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+def nest(var: str, n: int, prefix: str = '') -> str:
+    if n <= 0:
+        raise ValueError(n)
+    elif n == 1:
+        return f'{var}{n}'
+    else:
+        return f'{prefix}({nest(var, n - 1, prefix)}, {var}{n})'
+
+print(
+f"""\
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub trait ZippableLongest: Iterator + Sized {{
+    #[inline]
+    fn zip_longest<Iter: Iterator>(self, iter: Iter) -> ZipLongest<Self, Iter> {{
+        ZipLongest::new(self, iter)
+    }}
+
+    #[inline]
+    fn zip_longest_either<Iter: Iterator>(self, iter: Iter) -> ZipLongestEither<Self, Iter> {{
+        ZipLongestEither::new(self, iter)
+    }}
+
+    #[inline]
+    fn zip_longest_fill<Iter: Iterator>(self, iter: Iter, first_fill: Self::Item, second_fill: Iter::Item) -> ZipLongestFill<Self, Iter> {{
+        ZipLongestFill::new(self, iter, first_fill, second_fill)
+    }}
+
+    #[inline]
+    fn zip_longest_with<Iter, FF, SF>(self, iter: Iter, first_fill: FF, second_fill: SF) -> ZipLongestWith<Self, Iter, FF, SF>
+    where Iter: Iterator, FF: FnMut() -> Self::Item, SF: FnMut() -> Iter::Item {{
+        ZipLongestWith::new(self, iter, first_fill, second_fill)
+    }}
+"""
+)
+
+for n in range(3, 17):
+    print(
+f"""\
+    #[inline]
+    fn zip_longest{n}<{', '.join(f'I{i}' for i in range(2,n+1))}>(self, {', '.join(f'i{i}: I{i}' for i in range(2,n+1))}) -> impl Iterator<Item = (Self::Item, {', '.join(f'I{i}::Item' for i in range(2,n+1))})>
+    where {', '.join(f'I{i}: Iterator' for i in range(2,n+1))},
+          Self::Item: Default, {', '.join(f'I{i}::Item: Default' for i in range(2,n+1))} {{
+        zip_longest{n}(self{''.join(f', i{i}' for i in range(2,n+1))})
+    }}
+""")
+
+print("""\
+}
+
+impl<I> ZippableLongest for I where I: Iterator {}
+""")
+
+for n in range(3, 17):
+    print(
+f"""\
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest{n}<{', '.join(f'I{i}' for i in range(1,n+1))}>({', '.join(f'i{i}: I{i}' for i in range(1,n+1))}) -> impl Iterator<Item = ({', '.join(f'I{i}::Item' for i in range(1,n+1))})>
+where {', '.join(f'I{i}: Iterator' for i in range(1,n+1))},
+      {', '.join(f'I{i}::Item: Default' for i in range(1,n+1))} {{
+    {nest('i', n, 'ZipLongest::new')}.map(|{nest('v', n)}| ({', '.join(f'v{i}' for i in range(1,n+1))}))
+}}
+""")
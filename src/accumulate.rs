@@ -52,9 +52,7 @@ where I: Iterator, I::Item: Add<Output = I::Item>, I::Item: Clone {
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        let Some(value) = self.iter.next() else {
-            return None;
-        };
+        let value = self.iter.next()?;
 
         self.value = self.value.clone() + value;
 
@@ -68,12 +66,124 @@ where I: Iterator, I::Item: Default {
     Accumulate::new(iter)
 }
 
+#[derive(Debug)]
+pub struct AccumulateWith<I, F>
+where I: Iterator {
+    iter: I,
+    func: F,
+    value: Option<I::Item>,
+    emit_value: bool,
+}
+
+impl<I, F> Clone for AccumulateWith<I, F>
+where I: Iterator, I: Clone, I::Item: Clone, F: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            func: self.func.clone(),
+            value: self.value.clone(),
+            emit_value: self.emit_value,
+        }
+    }
+}
+
+impl<I, F> AccumulateWith<I, F>
+where I: Iterator, F: FnMut(I::Item, I::Item) -> I::Item {
+    #[inline]
+    pub fn new(iter: I, func: F) -> Self {
+        Self {
+            iter,
+            func,
+            value: None,
+            emit_value: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_initial(iter: I, initial: I::Item, func: F) -> Self {
+        Self {
+            iter,
+            func,
+            value: Some(initial),
+            emit_value: true,
+        }
+    }
+}
+
+impl<I, F> AccumulateWith<I, F>
+where I: Iterator, I::Item: Clone {
+    #[inline]
+    pub fn value(&self) -> Option<I::Item> {
+        self.value.clone()
+    }
+}
+
+impl<I, F> Iterator for AccumulateWith<I, F>
+where I: Iterator, I::Item: Clone, F: FnMut(I::Item, I::Item) -> I::Item {
+    type Item = I::Item;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let initial = usize::from(self.emit_value);
+
+        (lower.saturating_add(initial), upper.and_then(|upper| upper.checked_add(initial)))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count() + usize::from(self.emit_value)
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.emit_value {
+            self.emit_value = false;
+            return self.value.clone();
+        }
+
+        let value = self.iter.next()?;
+        let value = match self.value.take() {
+            Some(acc) => (self.func)(acc, value),
+            None => value,
+        };
+
+        self.value = Some(value.clone());
+
+        Some(value)
+    }
+}
+
+#[inline]
+pub fn accumulate_with<I, F>(iter: I, func: F) -> AccumulateWith<I, F>
+where I: Iterator, F: FnMut(I::Item, I::Item) -> I::Item {
+    AccumulateWith::new(iter, func)
+}
+
+#[inline]
+pub fn accumulate_with_initial<I, F>(iter: I, initial: I::Item, func: F) -> AccumulateWith<I, F>
+where I: Iterator, F: FnMut(I::Item, I::Item) -> I::Item {
+    AccumulateWith::with_initial(iter, initial, func)
+}
+
 pub trait Accumulatable: Iterator + Sized {
     #[inline]
     fn accumulate(self) -> Accumulate<Self>
     where Self::Item: Default {
         Accumulate::new(self)
     }
+
+    #[inline]
+    fn accumulate_with<F>(self, func: F) -> AccumulateWith<Self, F>
+    where F: FnMut(Self::Item, Self::Item) -> Self::Item {
+        AccumulateWith::new(self, func)
+    }
+
+    #[inline]
+    fn accumulate_with_initial<F>(self, initial: Self::Item, func: F) -> AccumulateWith<Self, F>
+    where F: FnMut(Self::Item, Self::Item) -> Self::Item {
+        AccumulateWith::with_initial(self, initial, func)
+    }
 }
 
 impl<I> Accumulatable for I where I: Iterator + Sized {}
@@ -1,30 +1,162 @@
 #[derive(Debug)]
 pub struct Combinations<I>
 where I: Iterator {
-    items: Vec<(I, I::Item)>,
+    iters: Vec<I>,
+    values: Vec<I::Item>,
+    started: bool,
     finished: bool,
 }
 
 impl<I> Combinations<I>
 where I: Iterator, I: Clone {
     pub fn new(mut iter: I, r: usize) -> Self {
-        let mut items = Vec::with_capacity(r);
+        let mut iters = Vec::with_capacity(r);
+        let mut values = Vec::with_capacity(r);
 
         for _ in 0..r {
             let Some(value) = iter.next() else {
                 return Self {
-                    items: vec![],
+                    iters: vec![],
+                    values: vec![],
+                    started: true,
                     finished: true,
                 };
             };
-            items.push((iter.clone(), value));
+            iters.push(iter.clone());
+            values.push(value);
         }
 
         Self {
-            items,
+            iters,
+            values,
+            started: false,
             finished: false,
         }
     }
+
+    // Starts at the rank-th combination, see combination_unrank(). This needs
+    // to count the input once to know its length.
+    pub fn from_rank(mut iter: I, r: usize, rank: usize) -> Self {
+        let Some(indices) = combination_unrank(iter.clone().count(), r, rank) else {
+            return Self {
+                iters: vec![],
+                values: vec![],
+                started: true,
+                finished: true,
+            };
+        };
+
+        let mut iters = Vec::with_capacity(r);
+        let mut values = Vec::with_capacity(r);
+        let mut next = 0;
+
+        for index in indices {
+            let value = iter.nth(index - next).expect("iterator shorter than its count");
+            next = index + 1;
+            iters.push(iter.clone());
+            values.push(value);
+        }
+
+        Self {
+            iters,
+            values,
+            started: false,
+            finished: false,
+        }
+    }
+
+    // Like next(), but returns the internal buffer instead of a fresh Vec.
+    pub fn next_slice(&mut self) -> Option<&[I::Item]> {
+        if self.finished {
+            return None;
+        }
+
+        if self.started {
+            self.advance();
+
+            if self.finished {
+                return None;
+            }
+        } else {
+            self.started = true;
+        }
+
+        Some(&self.values)
+    }
+
+    fn advance(&mut self) {
+        let n = self.values.len();
+
+        if n == 0 {
+            self.finished = true;
+            return;
+        }
+
+        /*
+         * The following could be just this, but that has some unnecessary
+         * clones of the iterator and checks the index == 0 potentially
+         * multiple times.
+
+        'outer: for index in (0..n).rev() {
+            let mut iter = self.iters[index].clone();
+
+            for pos in index..n {
+                let Some(value) = iter.next() else {
+                    if index == 0 {
+                        self.finished = true;
+                        break;
+                    }
+                    continue 'outer;
+                };
+                self.iters[pos] = iter.clone();
+                self.values[pos] = value;
+            }
+
+            break;
+        }
+         */
+
+        'outer: for index in (1..n).rev() {
+            let mut iter = self.iters[index].clone();
+
+            for pos in index..n - 1 {
+                let Some(value) = iter.next() else {
+                    continue 'outer;
+                };
+                self.iters[pos] = iter.clone();
+                self.values[pos] = value;
+            }
+
+            if let Some(value) = iter.next() {
+                // eliminate one unnecessary clone
+                self.iters[n - 1] = iter;
+                self.values[n - 1] = value;
+            } else {
+                continue 'outer;
+            }
+
+            return;
+        }
+
+        let mut iter = self.iters[0].clone();
+
+        for pos in 0..n - 1 {
+            let Some(value) = iter.next() else {
+                self.finished = true;
+                return;
+            };
+            self.iters[pos] = iter.clone();
+            self.values[pos] = value;
+        }
+
+        if let Some(value) = iter.next() {
+            // eliminate one unnecessary clone
+            self.iters[n - 1] = iter;
+            self.values[n - 1] = value;
+        } else {
+            self.finished = true;
+        }
+    }
 }
 
 impl<I> Clone for Combinations<I>
@@ -32,7 +164,9 @@ where I: Iterator, I: Clone, I::Item: Clone {
     #[inline]
     fn clone(&self) -> Self {
         Self {
-            items: self.items.clone(),
+            iters: self.iters.clone(),
+            values: self.values.clone(),
+            started: self.started,
             finished: self.finished,
         }
     }
@@ -40,84 +174,403 @@ where I: Iterator, I: Clone, I::Item: Clone {
 
 impl<I> Iterator for Combinations<I>
 where I: Iterator, I: Clone, I::Item: Clone {
-    // TODO: How to do this returning &[I::Item] instead of Vec<I::Item>?
     type Item = Vec<I::Item>;
 
-    // TODO: Is there a way to calculate size_hint()?
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
+        // Every position holds the iterator over the items after its value,
+        // so the combinations still to come can be counted per position.
+        let r = self.values.len();
+        let current = usize::from(!self.started);
+        let mut lower = current;
+        let mut upper = Some(current);
+
+        for (index, iter) in self.iters.iter().enumerate() {
+            let (iter_lower, iter_upper) = iter.size_hint();
+            lower = lower.saturating_add(binomial_usize(iter_lower, r - index).unwrap_or(usize::MAX));
+            upper = match (upper, iter_upper) {
+                (Some(upper), Some(iter_upper)) => binomial_usize(iter_upper, r - index)
+                    .and_then(|val| val.checked_add(upper)),
+                _ => None,
+            };
+        }
+
+        (lower, upper)
+    }
+
+    fn count(self) -> usize {
         if self.finished {
+            return 0;
+        }
+
+        let r = self.values.len();
+        let current = usize::from(!self.started);
+
+        self.iters.into_iter().enumerate().try_fold(current, |count, (index, iter)| {
+            binomial_usize(iter.count(), r - index)
+                .and_then(|val| val.checked_add(count))
+        }).expect("usize overflow")
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(<[I::Item]>::to_vec)
+    }
+}
+
+// Like count(), len() panics when the number of combinations doesn't fit
+// into usize.
+impl<I> ExactSizeIterator for Combinations<I>
+where I: ExactSizeIterator, I: Clone, I::Item: Clone {}
+
+// Combinations of a size known at compile time, yielded as arrays.
+pub struct ArrayCombinations<I, const K: usize>
+where I: Iterator {
+    combinations: Combinations<I>,
+}
+
+impl<I, const K: usize> ArrayCombinations<I, K>
+where I: Iterator, I: Clone {
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self {
+            combinations: Combinations::new(iter, K),
+        }
+    }
+}
+
+impl<I, const K: usize> Clone for ArrayCombinations<I, K>
+where I: Iterator, I: Clone, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            combinations: self.combinations.clone(),
+        }
+    }
+}
+
+impl<I, const K: usize> Iterator for ArrayCombinations<I, K>
+where I: Iterator, I: Clone, I::Item: Clone {
+    type Item = [I::Item; K];
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.combinations.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.combinations.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.combinations.next_slice()?;
+
+        Some(std::array::from_fn(|index| values[index].clone()))
+    }
+}
+
+pub(crate) fn binomial_usize(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k);
+    let mut res = 1u128;
+
+    for i in 0..k {
+        // res stays below usize::MAX here, so this can't overflow u128
+        res = res * (n - i) as u128 / (i + 1) as u128;
+        if res > usize::MAX as u128 {
             return None;
         }
+    }
 
-        let res = self.items.iter().map(|(_, v)| v.clone()).collect();
-        let n = self.items.len();
+    Some(res as usize)
+}
 
-        if n == 0 {
-            self.finished = true;
-        } else {
-            /*
-             * The following could be just this, but that has some unnecessary
-             * clones of the iterator and checks the index == 0 potentially
-             * multiple times.
-
-            'outer: for index in (0..n).rev() {
-                let mut iter = self.items[index].0.clone();
-
-                for item in &mut self.items[index..n] {
-                    let Some(value) = iter.next() else {
-                        if index == 0 {
-                            self.finished = true;
-                            break;
-                        }
-                        continue 'outer;
-                    };
-                    *item = (iter.clone(), value);
-                }
+// Advances the sorted indices of a k-combination of 0..n to the next one in
+// lexicographic order, the same order Combinations yields them in.
+pub fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
 
-                break;
+    for index in (0..k).rev() {
+        if indices[index] + k - index < n {
+            indices[index] += 1;
+            for next in index + 1..k {
+                indices[next] = indices[next - 1] + 1;
             }
-             */
-
-            'outer: for index in (1..n).rev() {
-                let mut iter = self.items[index].0.clone();
-
-                for item in &mut self.items[index..n - 1] {
-                    let Some(value) = iter.next() else {
-                        continue 'outer;
-                    };
-                    *item = (iter.clone(), value);
-                }
-
-                if let Some(value) = iter.next() {
-                    // eliminate one unnecessary clone
-                    self.items[n - 1] = (iter, value);
-                } else {
-                    continue 'outer;
-                }
+            return true;
+        }
+    }
+
+    false
+}
 
-                return Some(res);
+// Counterpart of next_combination(), steps back to the previous combination.
+pub fn prev_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+
+    for index in (0..k).rev() {
+        let lowest = if index == 0 { 0 } else { indices[index - 1] + 1 };
+        if indices[index] > lowest {
+            indices[index] -= 1;
+            // the remaining positions take the largest indices
+            for (pos, value) in indices[index + 1..].iter_mut().enumerate() {
+                *value = n - k + index + 1 + pos;
             }
+            return true;
+        }
+    }
+
+    false
+}
 
-            let mut iter = self.items[0].0.clone();
+// Indices of the rank-th k-combination of 0..n (combinatorial number system).
+// Ranks follow lexicographic order, the order Combinations yields them in.
+pub fn combination_unrank(n: usize, k: usize, mut rank: usize) -> Option<Vec<usize>> {
+    // a count that doesn't fit into usize is larger than any rank
+    if binomial_usize(n, k).is_some_and(|total| rank >= total) {
+        return None;
+    }
 
-            for item in &mut self.items[0..n - 1] {
-                let Some(value) = iter.next() else {
-                    self.finished = true;
-                    break;
-                };
-                *item = (iter.clone(), value);
+    let mut indices = Vec::with_capacity(k);
+    let mut candidate = 0;
+
+    for index in 0..k {
+        // skip the combinations that start with the chosen prefix and candidate
+        while let Some(count) = binomial_usize(n - 1 - candidate, k - 1 - index) {
+            if rank < count {
+                break;
             }
+            rank -= count;
+            candidate += 1;
+        }
+        indices.push(candidate);
+        candidate += 1;
+    }
 
-            if let Some(value) = iter.next() {
-                // eliminate one unnecessary clone
-                self.items[n - 1] = (iter, value);
-            } else {
+    Some(indices)
+}
+
+pub fn combination_rank(n: usize, indices: &[usize]) -> Option<usize> {
+    let k = indices.len();
+
+    if indices.iter().any(|&index| index >= n) || indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return None;
+    }
+
+    // sum up the combinations that come before, one prefix at a time
+    let mut rank = 0usize;
+    let mut candidate = 0;
+
+    for (pos, &index) in indices.iter().enumerate() {
+        for skipped in candidate..index {
+            rank = rank.checked_add(binomial_usize(n - 1 - skipped, k - 1 - pos)?)?;
+        }
+        candidate = index + 1;
+    }
+
+    Some(rank)
+}
+
+// Number of combinations that still come after the given one.
+fn combinations_after(n: usize, indices: &[usize]) -> Option<usize> {
+    let k = indices.len();
+    let mut after = 0usize;
+
+    for (pos, &index) in indices.iter().enumerate() {
+        after = after.checked_add(binomial_usize(n - 1 - index, k - pos)?)?;
+    }
+
+    Some(after)
+}
+
+// All k-combinations of the indices 0..n, without any source to clone.
+#[derive(Debug, Clone)]
+pub struct IndexCombinations {
+    n: usize,
+    indices: Vec<usize>,
+    started: bool,
+    finished: bool,
+}
+
+impl IndexCombinations {
+    #[inline]
+    pub fn new(n: usize, k: usize) -> Self {
+        if k > n {
+            return Self {
+                n,
+                indices: Vec::new(),
+                started: true,
+                finished: true,
+            };
+        }
+
+        Self {
+            n,
+            indices: (0..k).collect(),
+            started: false,
+            finished: false,
+        }
+    }
+
+    // See combination_unrank() for the order of the ranks.
+    pub fn from_rank(n: usize, k: usize, rank: usize) -> Self {
+        match combination_unrank(n, k, rank) {
+            Some(indices) => Self {
+                n,
+                indices,
+                started: false,
+                finished: false,
+            },
+            None => Self {
+                n,
+                indices: Vec::new(),
+                started: true,
+                finished: true,
+            },
+        }
+    }
+
+    fn nth_slice(&mut self, n: usize) -> Option<&[usize]> {
+        if self.finished {
+            return None;
+        }
+
+        let target = combination_rank(self.n, &self.indices)
+            .and_then(|rank| rank.checked_add(usize::from(self.started)))
+            .and_then(|rank| rank.checked_add(n));
+
+        // falls back to stepping past the ranks that fit into usize
+        let Some(target) = target else {
+            for _ in 0..n {
+                self.next_slice()?;
+            }
+            return self.next_slice();
+        };
+
+        let Some(indices) = combination_unrank(self.n, self.indices.len(), target) else {
+            self.finished = true;
+            return None;
+        };
+        self.indices = indices;
+        self.started = true;
+
+        Some(&self.indices)
+    }
+
+    // See Combinations::next_slice().
+    pub fn next_slice(&mut self) -> Option<&[usize]> {
+        if self.finished {
+            return None;
+        }
+
+        if self.started {
+            if !next_combination(&mut self.indices, self.n) {
                 self.finished = true;
+                return None;
             }
+        } else {
+            self.started = true;
+        }
+
+        Some(&self.indices)
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        if self.finished {
+            return Some(0);
+        }
+
+        combinations_after(self.n, &self.indices)?.checked_add(usize::from(!self.started))
+    }
+}
+
+impl Iterator for IndexCombinations {
+    type Item = Vec<usize>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
         }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.remaining().expect("usize overflow")
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(<[usize]>::to_vec)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.nth_slice(n).map(<[usize]>::to_vec)
+    }
+}
+
+// Combinations of the items of a slice, which only need to track indices.
+#[derive(Debug, Clone)]
+pub struct SliceCombinations<'a, T> {
+    data: &'a [T],
+    combinations: IndexCombinations,
+}
+
+impl<'a, T> SliceCombinations<'a, T> {
+    #[inline]
+    pub fn new(data: &'a [T], k: usize) -> Self {
+        Self {
+            data,
+            combinations: IndexCombinations::new(data.len(), k),
+        }
+    }
+
+    #[inline]
+    pub fn from_rank(data: &'a [T], k: usize, rank: usize) -> Self {
+        Self {
+            data,
+            combinations: IndexCombinations::from_rank(data.len(), k, rank),
+        }
+    }
+}
+
+impl<'a, T> Iterator for SliceCombinations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.combinations.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.combinations.count()
+    }
 
-        Some(res)
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data;
+        let combination = self.combinations.next_slice()?;
+
+        Some(combination.iter().map(|&index| &data[index]).collect())
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let data = self.data;
+        let combination = self.combinations.nth_slice(n)?;
+
+        Some(combination.iter().map(|&index| &data[index]).collect())
     }
 }
 
@@ -127,12 +580,56 @@ where I: Iterator, I: Clone {
     Combinations::new(iter, r)
 }
 
+#[inline]
+pub fn array_combinations<I, const K: usize>(iter: I) -> ArrayCombinations<I, K>
+where I: Iterator, I: Clone {
+    ArrayCombinations::new(iter)
+}
+
+#[inline]
+pub fn slice_combinations<T>(data: &[T], k: usize) -> SliceCombinations<'_, T> {
+    SliceCombinations::new(data, k)
+}
+
+#[inline]
+pub fn combinations_indices(n: usize, k: usize) -> IndexCombinations {
+    IndexCombinations::new(n, k)
+}
+
 pub trait Combinable: Iterator
 where Self: Sized + Clone {
     #[inline]
     fn combinations(self, r: usize) -> Combinations<Self> {
         Combinations::new(self, r)
     }
+
+    #[inline]
+    fn array_combinations<const K: usize>(self) -> ArrayCombinations<Self, K> {
+        ArrayCombinations::new(self)
+    }
 }
 
 impl<I> Combinable for I where I: Iterator + Clone {}
+
+pub trait SliceCombinable<'a> {
+    type Item;
+    fn combinations(&'a self, k: usize) -> SliceCombinations<'a, Self::Item>;
+}
+
+impl<'a, T> SliceCombinable<'a> for [T] {
+    type Item = T;
+
+    #[inline]
+    fn combinations(&'a self, k: usize) -> SliceCombinations<'a, Self::Item> {
+        SliceCombinations::new(self, k)
+    }
+}
+
+impl<'a, T, const N: usize> SliceCombinable<'a> for [T; N] {
+    type Item = T;
+
+    #[inline]
+    fn combinations(&'a self, k: usize) -> SliceCombinations<'a, Self::Item> {
+        SliceCombinations::new(self.as_slice(), k)
+    }
+}
@@ -0,0 +1,170 @@
+use crate::combinations::binomial_usize;
+
+#[derive(Debug)]
+pub struct CombinationsWithReplacement<I>
+where I: Iterator {
+    iters: Vec<I>,
+    values: Vec<I::Item>,
+    started: bool,
+    finished: bool,
+}
+
+impl<I> CombinationsWithReplacement<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    pub fn new(mut iter: I, r: usize) -> Self {
+        if r == 0 {
+            return Self {
+                iters: vec![],
+                values: vec![],
+                started: false,
+                finished: false,
+            };
+        }
+
+        let Some(value) = iter.next() else {
+            return Self {
+                iters: vec![],
+                values: vec![],
+                started: true,
+                finished: true,
+            };
+        };
+
+        Self {
+            iters: vec![iter; r],
+            values: vec![value; r],
+            started: false,
+            finished: false,
+        }
+    }
+
+    // See Combinations::next_slice().
+    pub fn next_slice(&mut self) -> Option<&[I::Item]> {
+        if self.finished {
+            return None;
+        }
+
+        if self.started {
+            self.advance();
+
+            if self.finished {
+                return None;
+            }
+        } else {
+            self.started = true;
+        }
+
+        Some(&self.values)
+    }
+
+    fn advance(&mut self) {
+        let n = self.values.len();
+
+        for index in (0..n).rev() {
+            let mut iter = self.iters[index].clone();
+            let Some(value) = iter.next() else {
+                continue;
+            };
+
+            for pos in index + 1..n {
+                self.iters[pos] = iter.clone();
+                self.values[pos] = value.clone();
+            }
+
+            // eliminate one unnecessary clone
+            self.iters[index] = iter;
+            self.values[index] = value;
+
+            return;
+        }
+
+        self.finished = true;
+    }
+}
+
+impl<I> Clone for CombinationsWithReplacement<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iters: self.iters.clone(),
+            values: self.values.clone(),
+            started: self.started,
+            finished: self.finished,
+        }
+    }
+}
+
+// number of non-decreasing sequences of length k drawn from n items
+#[inline]
+fn multichoose_usize(n: usize, k: usize) -> Option<usize> {
+    if k == 0 {
+        return Some(1);
+    }
+
+    binomial_usize(n.checked_add(k - 1)?, k)
+}
+
+impl<I> Iterator for CombinationsWithReplacement<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    type Item = Vec<I::Item>;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+
+        // Counted per position like in Combinations, just with multisets.
+        let r = self.values.len();
+        let current = usize::from(!self.started);
+        let mut lower = current;
+        let mut upper = Some(current);
+
+        for (index, iter) in self.iters.iter().enumerate() {
+            let (iter_lower, iter_upper) = iter.size_hint();
+            lower = lower.saturating_add(multichoose_usize(iter_lower, r - index).unwrap_or(usize::MAX));
+            upper = match (upper, iter_upper) {
+                (Some(upper), Some(iter_upper)) => multichoose_usize(iter_upper, r - index)
+                    .and_then(|val| val.checked_add(upper)),
+                _ => None,
+            };
+        }
+
+        (lower, upper)
+    }
+
+    fn count(self) -> usize {
+        if self.finished {
+            return 0;
+        }
+
+        let r = self.values.len();
+        let current = usize::from(!self.started);
+
+        self.iters.into_iter().enumerate().try_fold(current, |count, (index, iter)| {
+            multichoose_usize(iter.count(), r - index)
+                .and_then(|val| val.checked_add(count))
+        }).expect("usize overflow")
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(<[I::Item]>::to_vec)
+    }
+}
+
+#[inline]
+pub fn combinations_with_replacement<I>(iter: I, r: usize) -> CombinationsWithReplacement<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    CombinationsWithReplacement::new(iter, r)
+}
+
+pub trait CombinableWithReplacement: Iterator
+where Self: Sized + Clone, Self::Item: Clone {
+    #[inline]
+    fn combinations_with_replacement(self, r: usize) -> CombinationsWithReplacement<Self> {
+        CombinationsWithReplacement::new(self, r)
+    }
+}
+
+impl<I> CombinableWithReplacement for I where I: Iterator + Clone, I::Item: Clone {}
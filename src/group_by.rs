@@ -0,0 +1,170 @@
+use std::cell::RefCell;
+
+pub trait KeyFn<T> {
+    type Key: PartialEq + Clone;
+
+    fn key(&mut self, value: &T) -> Self::Key;
+}
+
+impl<T, K, F> KeyFn<T> for F
+where F: FnMut(&T) -> K, K: PartialEq + Clone {
+    type Key = K;
+
+    #[inline]
+    fn key(&mut self, value: &T) -> Self::Key {
+        self(value)
+    }
+}
+
+struct GroupByInner<I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    iter: I,
+    key: F,
+    current_key: Option<F::Key>,
+    // first item of the current group, not yet yielded by it
+    head: Option<I::Item>,
+    // lookahead item that starts the next group
+    pending: Option<(F::Key, I::Item)>,
+    in_group: bool,
+    index: usize,
+}
+
+impl<I, F> GroupByInner<I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    fn next_group(&mut self) -> Option<(F::Key, usize)> {
+        // Skip whatever is left of the current group. Groups are only valid
+        // while they are the current one, so there is nothing to buffer.
+        while self.in_group {
+            self.next_in_group(self.index);
+        }
+
+        let (key, item) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next()?;
+                (self.key.key(&item), item)
+            }
+        };
+
+        self.current_key = Some(key.clone());
+        self.head = Some(item);
+        self.in_group = true;
+        self.index += 1;
+
+        Some((key, self.index))
+    }
+
+    fn next_in_group(&mut self, index: usize) -> Option<I::Item> {
+        if index != self.index || !self.in_group {
+            return None;
+        }
+
+        if let Some(item) = self.head.take() {
+            return Some(item);
+        }
+
+        let Some(item) = self.iter.next() else {
+            self.in_group = false;
+            return None;
+        };
+
+        let key = self.key.key(&item);
+        if self.current_key.as_ref() == Some(&key) {
+            Some(item)
+        } else {
+            self.pending = Some((key, item));
+            self.in_group = false;
+            None
+        }
+    }
+}
+
+pub struct GroupBy<I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    inner: RefCell<GroupByInner<I, F>>,
+}
+
+impl<I, F> GroupBy<I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    #[inline]
+    pub fn new(iter: I, key: F) -> Self {
+        Self {
+            inner: RefCell::new(GroupByInner {
+                iter,
+                key,
+                current_key: None,
+                head: None,
+                pending: None,
+                in_group: false,
+                index: 0,
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn groups(&self) -> Groups<'_, I, F> {
+        Groups { parent: self }
+    }
+}
+
+impl<'a, I, F> IntoIterator for &'a GroupBy<I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    type Item = (F::Key, Group<'a, I, F>);
+    type IntoIter = Groups<'a, I, F>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.groups()
+    }
+}
+
+pub struct Groups<'a, I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    parent: &'a GroupBy<I, F>,
+}
+
+impl<'a, I, F> Iterator for Groups<'a, I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    type Item = (F::Key, Group<'a, I, F>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, index) = self.parent.inner.borrow_mut().next_group()?;
+
+        Some((key, Group { parent: self.parent, index }))
+    }
+}
+
+// A group yields its items lazily from the shared source iterator. Once the
+// next group is requested it is exhausted and yields nothing anymore.
+pub struct Group<'a, I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    parent: &'a GroupBy<I, F>,
+    index: usize,
+}
+
+impl<I, F> Iterator for Group<'_, I, F>
+where I: Iterator, F: KeyFn<I::Item> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.parent.inner.borrow_mut().next_in_group(self.index)
+    }
+}
+
+#[inline]
+pub fn group_by<I, K, F>(iter: I, key: F) -> GroupBy<I, F>
+where I: Iterator, F: FnMut(&I::Item) -> K, K: PartialEq + Clone {
+    GroupBy::new(iter, key)
+}
+
+pub trait Groupable: Iterator
+where Self: Sized {
+    #[inline]
+    fn group_by<K, F>(self, key: F) -> GroupBy<Self, F>
+    where F: FnMut(&Self::Item) -> K, K: PartialEq + Clone {
+        GroupBy::new(self, key)
+    }
+}
+
+impl<I> Groupable for I where I: Iterator {}
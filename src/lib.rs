@@ -1,12 +1,21 @@
 pub mod product;
 pub use product::*;
 
+pub mod multi_product;
+pub use multi_product::*;
+
 pub mod combinations;
 pub use combinations::*;
 
+pub mod combinations_with_replacement;
+pub use combinations_with_replacement::*;
+
 pub mod powerset;
 pub use powerset::*;
 
+pub mod subsets;
+pub use subsets::*;
+
 pub mod permutations;
 pub use permutations::*;
 
@@ -18,3 +27,9 @@ pub use zip_longest::*;
 
 pub mod accumulate;
 pub use accumulate::*;
+
+pub mod group_by;
+pub use group_by::*;
+
+pub mod windows;
+pub use windows::*;
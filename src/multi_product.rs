@@ -0,0 +1,232 @@
+#[derive(Debug)]
+pub struct MultiProduct<I>
+where I: Iterator {
+    // pristine copy of every input, used to restart it
+    origins: Vec<I>,
+    iters: Vec<I>,
+    values: Vec<I::Item>,
+    started: bool,
+    finished: bool,
+}
+
+impl<I> MultiProduct<I>
+where I: Iterator, I: Clone {
+    pub fn new(origins: Vec<I>) -> Self {
+        let mut iters = origins.clone();
+        let mut values = Vec::with_capacity(iters.len());
+
+        for iter in &mut iters {
+            let Some(value) = iter.next() else {
+                return Self {
+                    origins,
+                    iters: vec![],
+                    values: vec![],
+                    started: true,
+                    finished: true,
+                };
+            };
+            values.push(value);
+        }
+
+        Self {
+            origins,
+            iters,
+            values,
+            started: false,
+            finished: false,
+        }
+    }
+
+    // See Combinations::next_slice().
+    pub fn next_slice(&mut self) -> Option<&[I::Item]> {
+        if self.finished {
+            return None;
+        }
+
+        if self.started {
+            self.advance();
+
+            if self.finished {
+                return None;
+            }
+        } else {
+            self.started = true;
+        }
+
+        Some(&self.values)
+    }
+
+    fn advance(&mut self) {
+        // count up like an odometer, the last input changes fastest
+        for index in (0..self.iters.len()).rev() {
+            if let Some(value) = self.iters[index].next() {
+                self.values[index] = value;
+                return;
+            }
+
+            let mut iter = self.origins[index].clone();
+            let Some(value) = iter.next() else {
+                break;
+            };
+            self.iters[index] = iter;
+            self.values[index] = value;
+        }
+
+        self.finished = true;
+    }
+}
+
+impl<I> Clone for MultiProduct<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            origins: self.origins.clone(),
+            iters: self.iters.clone(),
+            values: self.values.clone(),
+            started: self.started,
+            finished: self.finished,
+        }
+    }
+}
+
+impl<I> Iterator for MultiProduct<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    type Item = Vec<I::Item>;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+
+        // Every position contributes its remaining items times the full
+        // lengths of all the positions after it.
+        let current = usize::from(!self.started);
+        let mut lower = current;
+        let mut upper = Some(current);
+        let mut tail_lower = 1usize;
+        let mut tail_upper = Some(1usize);
+
+        for (iter, origin) in self.iters.iter().zip(&self.origins).rev() {
+            let (iter_lower, iter_upper) = iter.size_hint();
+            let (origin_lower, origin_upper) = origin.size_hint();
+
+            lower = lower.saturating_add(iter_lower.saturating_mul(tail_lower));
+            upper = match (upper, iter_upper, tail_upper) {
+                (Some(upper), Some(iter_upper), Some(tail_upper)) => iter_upper
+                    .checked_mul(tail_upper)
+                    .and_then(|val| val.checked_add(upper)),
+                _ => None,
+            };
+
+            tail_lower = tail_lower.saturating_mul(origin_lower);
+            tail_upper = match (tail_upper, origin_upper) {
+                (Some(tail_upper), Some(origin_upper)) => tail_upper.checked_mul(origin_upper),
+                _ => None,
+            };
+        }
+
+        (lower, upper)
+    }
+
+    fn count(self) -> usize {
+        if self.finished {
+            return 0;
+        }
+
+        let current = usize::from(!self.started);
+
+        self.iters.into_iter().zip(self.origins).rev().try_fold((current, 1usize), |(count, tail), (iter, origin)| {
+            let count = iter.count().checked_mul(tail)?.checked_add(count)?;
+            let tail = tail.checked_mul(origin.count())?;
+            Some((count, tail))
+        }).expect("usize overflow").0
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(<[I::Item]>::to_vec)
+    }
+}
+
+pub struct ProductRepeat<I>
+where I: Iterator {
+    iter: MultiProduct<I>,
+}
+
+impl<I> ProductRepeat<I>
+where I: Iterator, I: Clone {
+    #[inline]
+    pub fn new(iter: I, repeat: usize) -> Self {
+        Self {
+            iter: MultiProduct::new(vec![iter; repeat]),
+        }
+    }
+
+    #[inline]
+    pub fn next_slice(&mut self) -> Option<&[I::Item]> {
+        self.iter.next_slice()
+    }
+}
+
+impl<I> Clone for ProductRepeat<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<I> Iterator for ProductRepeat<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+#[inline]
+pub fn product_repeat<I>(iter: I, repeat: usize) -> ProductRepeat<I>
+where I: Iterator, I: Clone {
+    ProductRepeat::new(iter, repeat)
+}
+
+#[inline]
+pub fn multi_product<T>(iters: T) -> MultiProduct<T::Item>
+where T: IntoIterator, T::Item: Iterator + Clone {
+    MultiProduct::new(iters.into_iter().collect())
+}
+
+pub trait MultiProductable: Iterator
+where Self: Sized, Self::Item: Iterator + Clone {
+    #[inline]
+    fn multi_product(self) -> MultiProduct<Self::Item> {
+        MultiProduct::new(self.collect())
+    }
+}
+
+impl<I> MultiProductable for I where I: Iterator, I::Item: Iterator + Clone {}
+
+pub trait ProductRepeatable: Iterator
+where Self: Sized + Clone {
+    #[inline]
+    fn product_repeat(self, repeat: usize) -> ProductRepeat<Self> {
+        ProductRepeat::new(self, repeat)
+    }
+}
+
+impl<I> ProductRepeatable for I where I: Iterator + Clone {}
@@ -1,8 +1,13 @@
+use std::iter::FusedIterator;
+
 #[derive(Debug)]
 pub struct Pairwise<I>
 where I: Iterator {
     iter: I,
+    // first item of the next pair from the front
     item: Option<I::Item>,
+    // second item of the next pair from the back
+    back: Option<I::Item>,
 }
 
 impl<I> Pairwise<I>
@@ -11,6 +16,7 @@ where I: Iterator {
     pub fn new(mut iter: I) -> Self {
         Self {
             item: iter.next(),
+            back: None,
             iter,
         }
     }
@@ -22,6 +28,7 @@ where I: Iterator, I: Clone, I::Item: Clone {
     fn clone(&self) -> Self {
         Self {
             item: self.item.clone(),
+            back: self.back.clone(),
             iter: self.iter.clone(),
         }
     }
@@ -37,7 +44,11 @@ where I: Iterator, I::Item: Clone {
             return (0, Some(0));
         }
 
-        self.iter.size_hint()
+        // every remaining item after the carried one completes a pair
+        let (lower, upper) = self.iter.size_hint();
+        let back = usize::from(self.back.is_some());
+
+        (lower.saturating_add(back), upper.and_then(|upper| upper.checked_add(back)))
     }
 
     #[inline]
@@ -46,38 +57,278 @@ where I: Iterator, I::Item: Clone {
             return 0;
         }
 
-        self.iter.count()
+        self.iter.count() + usize::from(self.back.is_some())
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        let Some(first) = &self.item else {
-            return None;
+        let first = self.item.take()?;
+
+        let second = match self.iter.next() {
+            Some(second) => second,
+            None => self.back.take()?,
         };
-        let first = first.clone();
-        self.item = self.iter.next();
+        self.item = Some(second.clone());
 
-        let Some(second) = &self.item else {
-            self.item = None;
-            return None;
+        Some((first, second))
+    }
+}
+
+impl<I> DoubleEndedIterator for Pairwise<I>
+where I: DoubleEndedIterator, I::Item: Clone {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.item.as_ref()?;
+
+        // once no pair is left the carried item is dropped, keeping this fused
+        let second = match self.back.take().or_else(|| self.iter.next_back()) {
+            Some(second) => second,
+            None => {
+                self.item = None;
+                return None;
+            }
         };
-        let second = second.clone();
+
+        let Some(first) = self.iter.next_back() else {
+            // only the carried front item is left, it starts the last pair
+            return Some((self.item.take()?, second));
+        };
+        self.back = Some(first.clone());
 
         Some((first, second))
     }
 }
 
+impl<I> ExactSizeIterator for Pairwise<I>
+where I: ExactSizeIterator, I::Item: Clone {}
+
+impl<I> FusedIterator for Pairwise<I>
+where I: Iterator, I::Item: Clone {}
+
+#[derive(Debug)]
+pub struct CircularPairwise<I>
+where I: Iterator {
+    iter: I,
+    first: Option<I::Item>,
+    item: Option<I::Item>,
+}
+
+impl<I> CircularPairwise<I>
+where I: Iterator, I::Item: Clone {
+    #[inline]
+    pub fn new(mut iter: I) -> Self {
+        let item = iter.next();
+
+        Self {
+            first: item.clone(),
+            item,
+            iter,
+        }
+    }
+}
+
+impl<I> Clone for CircularPairwise<I>
+where I: Iterator, I: Clone, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            first: self.first.clone(),
+            item: self.item.clone(),
+        }
+    }
+}
+
+impl<I> Iterator for CircularPairwise<I>
+where I: Iterator, I::Item: Clone {
+    type Item = (I::Item, I::Item);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.item.is_none() {
+            return (0, Some(0));
+        }
+
+        // one more for the pair that closes the circle
+        let (lower, upper) = self.iter.size_hint();
+        (lower.saturating_add(1), upper.and_then(|upper| upper.checked_add(1)))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.item.is_none() {
+            return 0;
+        }
+
+        self.iter.count() + 1
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.item.take()?;
+
+        let Some(second) = self.iter.next() else {
+            // close the circle with (last, first)
+            let second = self.first.take()?;
+            return Some((first, second));
+        };
+        self.item = Some(second.clone());
+
+        Some((first, second))
+    }
+}
+
+#[derive(Debug)]
+pub struct PairwiseSlice<'a, T> {
+    data: &'a [T],
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> PairwiseSlice<'a, T> {
+    #[inline]
+    pub fn new(data: &'a [T]) -> Self {
+        Self {
+            data,
+            start: 0,
+            end: data.len().saturating_sub(1),
+        }
+    }
+
+    #[inline]
+    pub fn circular(data: &'a [T]) -> Self {
+        Self {
+            data,
+            start: 0,
+            end: data.len(),
+        }
+    }
+
+    #[inline]
+    fn pair(&self, index: usize) -> (&'a T, &'a T) {
+        let data = self.data;
+        (&data[index], &data[(index + 1) % data.len()])
+    }
+}
+
+impl<T> Clone for PairwiseSlice<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a, T> Iterator for PairwiseSlice<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start >= self.end {
+            return None;
+        }
+
+        let pair = self.pair(self.start);
+        self.start += 1;
+
+        Some(pair)
+    }
+}
+
+impl<T> DoubleEndedIterator for PairwiseSlice<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        Some(self.pair(self.end))
+    }
+}
+
+impl<T> ExactSizeIterator for PairwiseSlice<'_, T> {}
+
 #[inline]
 pub fn pairwise<I>(iter: I) -> Pairwise<I>
 where I: Iterator {
     Pairwise::new(iter)
 }
 
+#[inline]
+pub fn circular_pairwise<I>(iter: I) -> CircularPairwise<I>
+where I: Iterator, I::Item: Clone {
+    CircularPairwise::new(iter)
+}
+
+#[inline]
+pub fn pairwise_slice<T>(data: &[T]) -> PairwiseSlice<'_, T> {
+    PairwiseSlice::new(data)
+}
+
+#[inline]
+pub fn circular_pairwise_slice<T>(data: &[T]) -> PairwiseSlice<'_, T> {
+    PairwiseSlice::circular(data)
+}
+
 pub trait Pairwiseable: Iterator
 where Self: Sized {
     #[inline]
     fn pairwise(self) -> Pairwise<Self> {
         Pairwise::new(self)
     }
+
+    #[inline]
+    fn circular_pairwise(self) -> CircularPairwise<Self>
+    where Self::Item: Clone {
+        CircularPairwise::new(self)
+    }
 }
 
 impl<I> Pairwiseable for I where I: Iterator {}
+
+pub trait SlicePairwiseable<'a> {
+    type Item;
+    fn pairwise(&'a self) -> PairwiseSlice<'a, Self::Item>;
+    fn circular_pairwise(&'a self) -> PairwiseSlice<'a, Self::Item>;
+}
+
+impl<'a, T> SlicePairwiseable<'a> for [T] {
+    type Item = T;
+
+    #[inline]
+    fn pairwise(&'a self) -> PairwiseSlice<'a, Self::Item> {
+        PairwiseSlice::new(self)
+    }
+
+    #[inline]
+    fn circular_pairwise(&'a self) -> PairwiseSlice<'a, Self::Item> {
+        PairwiseSlice::circular(self)
+    }
+}
+
+impl<'a, T, const N: usize> SlicePairwiseable<'a> for [T; N] {
+    type Item = T;
+
+    #[inline]
+    fn pairwise(&'a self) -> PairwiseSlice<'a, Self::Item> {
+        PairwiseSlice::new(self.as_slice())
+    }
+
+    #[inline]
+    fn circular_pairwise(&'a self) -> PairwiseSlice<'a, Self::Item> {
+        PairwiseSlice::circular(self.as_slice())
+    }
+}
@@ -1,25 +1,230 @@
 #[derive(Debug, Clone)]
-pub struct Permutations<'a, T> {
-    data: &'a [T],
-    permutation: Vec<&'a T>,
+pub struct IndexPermutations {
+    n: usize,
+    permutation: Vec<usize>,
     indices: Vec<usize>,
     index: usize,
     count: usize,
 }
 
+impl IndexPermutations {
+    #[inline]
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            permutation: Vec::with_capacity(n),
+            indices: vec![0; n],
+            index: 1,
+            count: 0,
+        }
+    }
+
+    // Starts at the rank-th permutation in the order Heap's algorithm yields
+    // them, which is the order of this iterator (and of Permutations).
+    pub fn from_rank(n: usize, rank: usize) -> Self {
+        let mut permutations = Self::new(n);
+        if let Some(rank) = rank.checked_sub(1) {
+            // a rank past the end leaves the iterator exhausted
+            permutations.seek(rank);
+        }
+
+        permutations
+    }
+
+    // Puts the iterator into the state right after yielding the rank-th
+    // permutation. Heap's algorithm keeps a counter per position that
+    // together form the rank in the factorial number system, and a full run
+    // over the first k positions always rearranges them the same way, so
+    // whole runs can be skipped by applying that rearrangement directly.
+    fn seek(&mut self, rank: usize) -> bool {
+        let n = self.n;
+
+        if let Some(size) = factorial_usize(n) && rank >= size {
+            self.permutation.clear();
+            self.indices.fill(0);
+            self.index = n;
+            self.count = size;
+            return false;
+        }
+
+        let digits = heap_digits(n, rank);
+
+        self.permutation = heap_permutation(&digits);
+        self.indices = digits;
+        self.index = 1;
+        self.count = rank.saturating_add(1);
+
+        true
+    }
+
+    fn nth_slice(&mut self, n: usize) -> Option<&[usize]> {
+        if n == 0 {
+            return self.next_slice();
+        }
+
+        // count is the rank of the next permutation, once that saturates the
+        // ranks don't fit into usize anymore and this falls back to stepping
+        let target = self.count.checked_add(n).filter(|_| self.count < usize::MAX);
+        let Some(rank) = target else {
+            for _ in 0..n {
+                self.next_slice()?;
+            }
+            return self.next_slice();
+        };
+
+        if !self.seek(rank) {
+            return None;
+        }
+
+        Some(&self.permutation)
+    }
+
+    // See Combinations::next_slice().
+    pub fn next_slice(&mut self) -> Option<&[usize]> {
+        // Heap's algorithm
+        if self.count == 0 {
+            self.permutation.extend(0..self.n);
+            self.count = 1;
+            Some(&self.permutation)
+        } else {
+            let n = self.n;
+
+            while self.index < n {
+                if self.indices[self.index] < self.index {
+                    if (self.index & 1) == 0 {
+                        self.permutation.swap(0, self.index);
+                    } else {
+                        self.permutation.swap(self.indices[self.index], self.index);
+                    }
+
+                    self.indices[self.index] += 1;
+                    self.index = 1;
+                    self.count = self.count.saturating_add(1);
+
+                    return Some(&self.permutation);
+                } else {
+                    self.indices[self.index] = 0;
+                    self.index += 1;
+                }
+            }
+
+            self.permutation.clear();
+
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Permutations<'a, T> {
+    data: &'a [T],
+    permutations: IndexPermutations,
+}
+
 impl<'a, T> Permutations<'a, T> {
     #[inline]
     pub fn new(data: &'a [T]) -> Self {
         Self {
             data,
-            permutation: Vec::with_capacity(data.len()),
-            indices: vec![0; data.len()],
-            index: 1,
-            count: 0,
+            permutations: IndexPermutations::new(data.len()),
+        }
+    }
+
+    // See IndexPermutations::from_rank().
+    #[inline]
+    pub fn from_rank(data: &'a [T], rank: usize) -> Self {
+        Self {
+            data,
+            permutations: IndexPermutations::from_rank(data.len(), rank),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IntoPermutations<T> {
+    data: Vec<T>,
+    permutations: IndexPermutations,
+}
+
+impl<T> IntoPermutations<T> {
+    #[inline]
+    pub fn new(data: Vec<T>) -> Self {
+        Self {
+            permutations: IndexPermutations::new(data.len()),
+            data,
         }
     }
 }
 
+// The swap Heap's algorithm does at the given level (the position that is
+// swapped with) for the given number of swaps already done there.
+#[inline]
+fn heap_swap(permutation: &mut [usize], level: usize, swap: usize) {
+    if level & 1 == 0 {
+        permutation.swap(0, level);
+    } else {
+        permutation.swap(swap, level);
+    }
+}
+
+#[inline]
+fn rearrange(data: &mut [usize], run: &[usize]) {
+    let old = data.to_vec();
+    for (value, &index) in data.iter_mut().zip(run) {
+        *value = old[index];
+    }
+}
+
+// The rearrangement a full run over the first level positions does, runs
+// caches the ones of all levels computed so far.
+fn heap_run(runs: &mut Vec<Vec<usize>>, level: usize) -> &[usize] {
+    if runs.is_empty() {
+        runs.extend([Vec::new(), vec![0]]);
+    }
+
+    while runs.len() <= level {
+        let size = runs.len();
+        let mut run: Vec<usize> = (0..size).collect();
+
+        for swap in 0..size - 1 {
+            rearrange(&mut run[..size - 1], &runs[size - 1]);
+            heap_swap(&mut run, size - 1, swap);
+        }
+        rearrange(&mut run[..size - 1], &runs[size - 1]);
+        runs.push(run);
+    }
+
+    &runs[level]
+}
+
+// The counters of Heap's algorithm after yielding the rank-th permutation,
+// which are the digits of rank in the factorial number system.
+fn heap_digits(n: usize, rank: usize) -> Vec<usize> {
+    let mut digits = vec![0; n];
+    for (index, digit) in digits.iter_mut().enumerate().skip(1) {
+        // the factorials that don't fit into usize are larger than rank
+        if let Some(size) = factorial_usize(index) {
+            *digit = rank / size % (index + 1);
+        }
+    }
+
+    digits
+}
+
+fn heap_permutation(digits: &[usize]) -> Vec<usize> {
+    let mut runs = Vec::new();
+    let mut permutation: Vec<usize> = (0..digits.len()).collect();
+
+    for (level, &digit) in digits.iter().enumerate().rev() {
+        for swap in 0..digit {
+            rearrange(&mut permutation[..level], heap_run(&mut runs, level));
+            heap_swap(&mut permutation, level, swap);
+        }
+    }
+
+    permutation
+}
+
 #[cfg(target_pointer_width = "16")]
 #[inline]
 fn factorial_usize(n: usize) -> Option<usize> {
@@ -98,13 +303,148 @@ fn factorial_usize(n: usize) -> Option<usize> {
     Some(res)
 }
 
+impl Iterator for IndexPermutations {
+    type Item = Vec<usize>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = factorial_usize(self.n);
+        match size {
+            Some(size) => {
+                let remaining = size - self.count;
+                (remaining, Some(remaining))
+            }
+            None => (usize::MAX, None)
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        let size = factorial_usize(self.n).expect("usize overflow");
+        size - self.count
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(<[usize]>::to_vec)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.nth_slice(n).map(<[usize]>::to_vec)
+    }
+}
+
 impl<'a, T> Iterator for Permutations<'a, T> {
     type Item = Vec<&'a T>;
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = factorial_usize(self.data.len());
-        match size {
+        self.permutations.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.permutations.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data;
+        let permutation = self.permutations.next_slice()?;
+
+        Some(permutation.iter().map(|&index| &data[index]).collect())
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let data = self.data;
+        let permutation = self.permutations.nth_slice(n)?;
+
+        Some(permutation.iter().map(|&index| &data[index]).collect())
+    }
+}
+
+impl<T> Iterator for IntoPermutations<T>
+where T: Clone {
+    type Item = Vec<T>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.permutations.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.permutations.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let permutation = self.permutations.next_slice()?;
+
+        Some(permutation.iter().map(|&index| self.data[index].clone()).collect())
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let permutation = self.permutations.nth_slice(n)?;
+
+        Some(permutation.iter().map(|&index| self.data[index].clone()).collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartialPermutations<'a, T> {
+    data: &'a [T],
+    r: usize,
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+    count: usize,
+    finished: bool,
+}
+
+impl<'a, T> PartialPermutations<'a, T> {
+    #[inline]
+    pub fn new(data: &'a [T], r: usize) -> Self {
+        let n = data.len();
+
+        Self {
+            data,
+            r,
+            indices: (0..n).collect(),
+            cycles: if r <= n { (n - r + 1..=n).rev().collect() } else { vec![] },
+            count: 0,
+            finished: r > n,
+        }
+    }
+
+    #[inline]
+    fn total(&self) -> Option<usize> {
+        permutations_usize(self.data.len(), self.r)
+    }
+
+    #[inline]
+    fn permutation(&self) -> Vec<&'a T> {
+        self.indices[..self.r].iter().map(|&index| &self.data[index]).collect()
+    }
+}
+
+#[inline]
+fn permutations_usize(n: usize, r: usize) -> Option<usize> {
+    if r > n {
+        return Some(0);
+    }
+
+    (n - r + 1..=n).try_fold(1usize, |res, i| res.checked_mul(i))
+}
+
+impl<'a, T> Iterator for PartialPermutations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.total() {
             Some(size) => {
                 let remaining = size - self.count;
                 (remaining, Some(remaining))
@@ -115,53 +455,343 @@ impl<'a, T> Iterator for Permutations<'a, T> {
 
     #[inline]
     fn count(self) -> usize {
-        let size = factorial_usize(self.data.len()).expect("usize overflow");
+        let size = self.total().expect("usize overflow");
         size - self.count
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Heap's algorithm
+        if self.finished {
+            return None;
+        }
+
         if self.count == 0 {
-            self.permutation.extend(self.data.iter());
             self.count = 1;
-            Some(self.permutation.clone())
-        } else {
-            let n = self.data.len();
+            return Some(self.permutation());
+        }
 
-            while self.index < n {
-                if self.indices[self.index] < self.index {
-                    if (self.index & 1) == 0 {
-                        self.permutation.swap(0, self.index);
-                    } else {
-                        self.permutation.swap(self.indices[self.index], self.index);
-                    }
+        // same cycle based algorithm as Python's itertools.permutations()
+        let n = self.data.len();
 
-                    self.indices[self.index] += 1;
-                    self.index = 1;
-                    self.count += 1;
+        for index in (0..self.r).rev() {
+            self.cycles[index] -= 1;
 
-                    return Some(self.permutation.clone());
-                } else {
-                    self.indices[self.index] = 0;
-                    self.index += 1;
+            if self.cycles[index] == 0 {
+                self.indices[index..].rotate_left(1);
+                self.cycles[index] = n - index;
+            } else {
+                let other = n - self.cycles[index];
+                self.indices.swap(index, other);
+                self.count += 1;
+
+                return Some(self.permutation());
+            }
+        }
+
+        self.finished = true;
+
+        None
+    }
+}
+
+// Indices of the rank-th permutation of 0..n in the order Permutations and
+// IndexPermutations yield them (Heap's algorithm).
+pub fn permutation_unrank(n: usize, rank: usize) -> Option<Vec<usize>> {
+    if let Some(total) = factorial_usize(n) && rank >= total {
+        return None;
+    }
+
+    Some(heap_permutation(&heap_digits(n, rank)))
+}
+
+pub fn permutation_rank(indices: &[usize]) -> Option<usize> {
+    let n = indices.len();
+    let mut seen = vec![false; n];
+
+    for &index in indices {
+        if index >= n || seen[index] {
+            return None;
+        }
+        seen[index] = true;
+    }
+
+    // redo the levels from the top, the item that has to end up at a level's
+    // position tells how many swaps were done there
+    let mut runs = Vec::new();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    let mut rank = 0usize;
+
+    for level in (1..n).rev() {
+        let mut digit = 0;
+        while permutation[level] != indices[level] {
+            rearrange(&mut permutation[..level], heap_run(&mut runs, level));
+            heap_swap(&mut permutation, level, digit);
+            digit += 1;
+        }
+
+        if digit > 0 {
+            rank = rank.checked_add(digit.checked_mul(factorial_usize(level)?)?)?;
+        }
+    }
+
+    Some(rank)
+}
+
+// Indices of the rank-th permutation of 0..n in lexicographic order, using the
+// factorial number system (Lehmer code). This is the order of next_permutation()
+// and LexicographicPermutations (for distinct items).
+pub fn lexicographic_permutation_unrank(n: usize, mut rank: usize) -> Option<Vec<usize>> {
+    if let Some(total) = factorial_usize(n) && rank >= total {
+        return None;
+    }
+
+    let mut remaining: Vec<usize> = (0..n).collect();
+    let mut indices = Vec::with_capacity(n);
+
+    for pos in 0..n {
+        let digit = match factorial_usize(n - 1 - pos) {
+            Some(size) => {
+                let digit = rank / size;
+                rank %= size;
+                digit
+            }
+            // larger than any rank that fits into usize
+            None => 0,
+        };
+        indices.push(remaining.remove(digit));
+    }
+
+    Some(indices)
+}
+
+pub fn lexicographic_permutation_rank(indices: &[usize]) -> Option<usize> {
+    let n = indices.len();
+    let mut seen = vec![false; n];
+    let mut rank = 0usize;
+
+    for (pos, &index) in indices.iter().enumerate() {
+        if index >= n || seen[index] {
+            return None;
+        }
+        seen[index] = true;
+
+        // Lehmer code digit: smaller indices that are still unused
+        let digit = seen[..index].iter().filter(|&&used| !used).count();
+        if digit > 0 {
+            rank = rank.checked_add(digit.checked_mul(factorial_usize(n - 1 - pos)?)?)?;
+        }
+    }
+
+    Some(rank)
+}
+
+pub fn next_permutation<T>(data: &mut [T]) -> bool
+where T: Ord {
+    let n = data.len();
+    if n < 2 {
+        return false;
+    }
+
+    let mut index = n - 1;
+    while index > 0 && data[index - 1] >= data[index] {
+        index -= 1;
+    }
+
+    if index == 0 {
+        // wrap around to the first permutation
+        data.reverse();
+        return false;
+    }
+
+    let mut other = n - 1;
+    while data[other] <= data[index - 1] {
+        other -= 1;
+    }
+
+    data.swap(index - 1, other);
+    data[index..].reverse();
+
+    true
+}
+
+pub fn prev_permutation<T>(data: &mut [T]) -> bool
+where T: Ord {
+    let n = data.len();
+    if n < 2 {
+        return false;
+    }
+
+    let mut index = n - 1;
+    while index > 0 && data[index - 1] <= data[index] {
+        index -= 1;
+    }
+
+    if index == 0 {
+        // wrap around to the last permutation
+        data.reverse();
+        return false;
+    }
+
+    let mut other = n - 1;
+    while data[other] >= data[index - 1] {
+        other -= 1;
+    }
+
+    data.swap(index - 1, other);
+    data[index..].reverse();
+
+    true
+}
+
+#[derive(Debug, Clone)]
+pub struct LexicographicPermutations<'a, T> {
+    // the permutation yielded next, advanced right when the previous one is
+    // yielded so finished is always up to date
+    permutation: Vec<&'a T>,
+    yielded: usize,
+    finished: bool,
+}
+
+impl<'a, T> LexicographicPermutations<'a, T>
+where T: Ord {
+    #[inline]
+    pub fn new(data: &'a [T]) -> Self {
+        let mut permutation: Vec<&'a T> = data.iter().collect();
+        permutation.sort();
+
+        Self {
+            permutation,
+            yielded: 0,
+            finished: false,
+        }
+    }
+
+    // Starts at the rank-th permutation this iterator yields, i.e. ranks count
+    // distinct permutations only. For distinct items this is the same order
+    // as lexicographic_permutation_unrank().
+    pub fn from_rank(data: &'a [T], mut rank: usize) -> Self {
+        let mut remaining: Vec<&'a T> = data.iter().collect();
+        remaining.sort();
+
+        if let Some(total) = multiset_permutations(&remaining) && rank >= total {
+            return Self {
+                permutation: remaining,
+                yielded: total,
+                finished: true,
+            };
+        }
+
+        let yielded = rank;
+        let mut permutation = Vec::with_capacity(remaining.len());
+
+        while !remaining.is_empty() {
+            let mut index = 0;
+
+            loop {
+                // number of permutations that continue with remaining[index]
+                let mut rest = remaining.clone();
+                rest.remove(index);
+
+                match multiset_permutations(&rest) {
+                    Some(count) if rank >= count => {
+                        rank -= count;
+                        let value = remaining[index];
+                        while remaining[index] == value {
+                            index += 1;
+                        }
+                    }
+                    _ => break,
                 }
             }
 
-            self.permutation.clear();
+            permutation.push(remaining.remove(index));
+        }
 
-            None
+        Self {
+            permutation,
+            yielded,
+            finished: false,
+        }
+    }
+}
+
+// Number of distinct permutations of the sorted items.
+fn multiset_permutations<T>(sorted: &[T]) -> Option<usize>
+where T: PartialEq {
+    let mut res = 1usize;
+    let mut run = 0;
+
+    for (index, value) in sorted.iter().enumerate() {
+        run = if index > 0 && sorted[index - 1] == *value { run + 1 } else { 1 };
+
+        // appending an item to a run of equal ones (exact division)
+        let next = res as u128 * (index + 1) as u128 / run as u128;
+        res = usize::try_from(next).ok()?;
+    }
+
+    Some(res)
+}
+
+impl<'a, T> Iterator for LexicographicPermutations<'a, T>
+where T: Ord {
+    type Item = Vec<&'a T>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+
+        // duplicates are skipped, so n! is only an upper bound
+        let upper = factorial_usize(self.permutation.len()).map(|size| size - self.yielded);
+        (1, upper)
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
         }
+
+        let permutation = self.permutation.clone();
+        self.finished = !next_permutation(&mut self.permutation);
+        self.yielded += 1;
+
+        Some(permutation)
     }
 }
 
+#[inline]
+pub fn lexicographic_permutations<'a, T>(data: &'a [T]) -> LexicographicPermutations<'a, T>
+where T: Ord {
+    LexicographicPermutations::new(data)
+}
+
 #[inline]
 pub fn permutations<'a, T>(data: &'a [T]) -> Permutations<'a, T> {
     Permutations::new(data)
 }
 
+#[inline]
+pub fn partial_permutations<'a, T>(data: &'a [T], r: usize) -> PartialPermutations<'a, T> {
+    PartialPermutations::new(data, r)
+}
+
+#[inline]
+pub fn index_permutations(n: usize) -> IndexPermutations {
+    IndexPermutations::new(n)
+}
+
+#[inline]
+pub fn into_permutations<I>(iter: I) -> IntoPermutations<I::Item>
+where I: IntoIterator {
+    IntoPermutations::new(iter.into_iter().collect())
+}
+
 pub trait Permutable<'a> {
     type Item;
     fn permutations(&'a self) -> Permutations<'a, Self::Item>;
+    fn partial_permutations(&'a self, r: usize) -> PartialPermutations<'a, Self::Item>;
+    fn lexicographic_permutations(&'a self) -> LexicographicPermutations<'a, Self::Item>
+    where Self::Item: Ord;
 }
 
 impl<'a, T> Permutable<'a> for [T] {
@@ -171,6 +801,17 @@ impl<'a, T> Permutable<'a> for [T] {
     fn permutations(&'a self) -> Permutations<'a, Self::Item> {
         Permutations::new(self)
     }
+
+    #[inline]
+    fn partial_permutations(&'a self, r: usize) -> PartialPermutations<'a, Self::Item> {
+        PartialPermutations::new(self, r)
+    }
+
+    #[inline]
+    fn lexicographic_permutations(&'a self) -> LexicographicPermutations<'a, Self::Item>
+    where Self::Item: Ord {
+        LexicographicPermutations::new(self)
+    }
 }
 
 impl<'a, T, const N: usize> Permutable<'a> for [T; N] {
@@ -180,4 +821,25 @@ impl<'a, T, const N: usize> Permutable<'a> for [T; N] {
     fn permutations(&'a self) -> Permutations<'a, Self::Item> {
         Permutations::new(self.as_slice())
     }
+
+    #[inline]
+    fn partial_permutations(&'a self, r: usize) -> PartialPermutations<'a, Self::Item> {
+        PartialPermutations::new(self.as_slice(), r)
+    }
+
+    #[inline]
+    fn lexicographic_permutations(&'a self) -> LexicographicPermutations<'a, Self::Item>
+    where Self::Item: Ord {
+        LexicographicPermutations::new(self.as_slice())
+    }
 }
+
+pub trait IntoPermutable: IntoIterator
+where Self: Sized {
+    #[inline]
+    fn into_permutations(self) -> IntoPermutations<Self::Item> {
+        IntoPermutations::new(self.into_iter().collect())
+    }
+}
+
+impl<I> IntoPermutable for I where I: IntoIterator {}
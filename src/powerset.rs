@@ -1,76 +1,238 @@
-use crate::Combinations;
+use std::ops::{Bound, RangeBounds};
 
-pub struct Powerset<I>
+use crate::combinations::{binomial_usize, combination_rank, next_combination, prev_combination};
+
+// The input is buffered once, after which the subsets are produced layer by
+// layer (by size) from the indices alone. From the back the layers are walked
+// in reverse, i.e. the largest subsets come first.
+pub struct PowersetRange<I>
 where I: Iterator {
-    iter: I,
-    combinations: Combinations<I>,
-    r: usize,
+    data: Vec<I::Item>,
+    front: Vec<usize>,
+    back: Vec<usize>,
     finished: bool,
 }
 
-impl<I> Powerset<I>
-where I: Iterator, I: Clone {
+impl<I> PowersetRange<I>
+where I: Iterator {
+    pub fn new<R>(iter: I, range: R) -> Self
+    where R: RangeBounds<usize> {
+        let data: Vec<I::Item> = iter.collect();
+        let n = data.len();
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        // inclusive, clamped to the length of the input
+        let last = match range.end_bound() {
+            Bound::Included(&end) => Some(end.min(n)),
+            Bound::Excluded(&end) => end.checked_sub(1).map(|end| end.min(n)),
+            Bound::Unbounded => Some(n),
+        };
+
+        match (start, last) {
+            (Some(start), Some(last)) if start <= last => Self {
+                data,
+                front: (0..start).collect(),
+                back: (n - last..n).collect(),
+                finished: false,
+            },
+            _ => Self {
+                data,
+                front: Vec::new(),
+                back: Vec::new(),
+                finished: true,
+            },
+        }
+    }
+
     #[inline]
-    pub fn new(iter: I) -> Self {
-        Self {
-            combinations: Combinations::new(iter.clone(), 0),
-            iter,
-            r: 0,
-            finished: false,
+    fn remaining(&self) -> Option<usize> {
+        if self.finished {
+            return Some(0);
+        }
+
+        // counted from the layer of the front on, so that ranges of large
+        // inputs can still be counted
+        let n = self.data.len();
+        let front = combination_rank(n, &self.front)?;
+        let back = combination_rank(n, &self.back)?;
+
+        if self.front.len() == self.back.len() {
+            return (back - front).checked_add(1);
+        }
+
+        let mut remaining = (binomial_usize(n, self.front.len())? - front)
+            .checked_add(back)?
+            .checked_add(1)?;
+        for k in self.front.len() + 1..self.back.len() {
+            remaining = remaining.checked_add(binomial_usize(n, k)?)?;
         }
+
+        Some(remaining)
+    }
+
+    #[inline]
+    fn subset(&self, indices: &[usize]) -> Vec<I::Item>
+    where I::Item: Clone {
+        indices.iter().map(|&index| self.data[index].clone()).collect()
     }
 }
 
-impl<I> Clone for Powerset<I>
-where I: Iterator, I: Clone, I::Item: Clone {
+impl<I> Clone for PowersetRange<I>
+where I: Iterator, I::Item: Clone {
     #[inline]
     fn clone(&self) -> Self {
         Self {
-            iter: self.iter.clone(),
-            combinations: self.combinations.clone(),
-            r: self.r,
+            data: self.data.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
             finished: self.finished,
         }
     }
 }
 
-impl<I> Iterator for Powerset<I>
-where I: Iterator, I: Clone, I::Item: Clone {
+// There is no ExactSizeIterator, since from 64 items on (on 64 bit) the
+// number of subsets doesn't fit into usize anymore.
+impl<I> Iterator for PowersetRange<I>
+where I: Iterator, I::Item: Clone {
     type Item = Vec<I::Item>;
 
-    // TODO: Is there a way to calculate size_hint()?
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.remaining().expect("usize overflow")
+    }
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.finished {
             return None;
         }
 
-        if let Some(value) = self.combinations.next() {
-            return Some(value);
+        let value = self.subset(&self.front);
+
+        if self.front == self.back {
+            self.finished = true;
+        } else if !next_combination(&mut self.front, self.data.len()) {
+            // continue with the first subset of the next layer
+            let r = self.front.len() + 1;
+            self.front.clear();
+            self.front.extend(0..r);
+        }
+
+        Some(value)
+    }
+}
+
+impl<I> DoubleEndedIterator for PowersetRange<I>
+where I: Iterator, I::Item: Clone {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let value = self.subset(&self.back);
+
+        if self.front == self.back {
+            self.finished = true;
+        } else if !prev_combination(&mut self.back, self.data.len()) {
+            // continue with the last subset of the previous layer
+            let n = self.data.len();
+            let r = self.back.len() - 1;
+            self.back.clear();
+            self.back.extend(n - r..n);
+        }
+
+        Some(value)
+    }
+}
+
+pub struct Powerset<I>
+where I: Iterator {
+    inner: PowersetRange<I>,
+}
+
+impl<I> Powerset<I>
+where I: Iterator {
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self {
+            inner: PowersetRange::new(iter, ..),
+        }
+    }
+}
+
+impl<I> Clone for Powerset<I>
+where I: Iterator, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
         }
+    }
+}
 
-        self.r += 1;
-        self.combinations = Combinations::new(self.iter.clone(), self.r);
+impl<I> Iterator for Powerset<I>
+where I: Iterator, I::Item: Clone {
+    type Item = Vec<I::Item>;
 
-        let value = self.combinations.next();
-        self.finished = value.is_none();
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 
-        value
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<I> DoubleEndedIterator for Powerset<I>
+where I: Iterator, I::Item: Clone {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
     }
 }
 
 #[inline]
 pub fn powerset<I>(iter: I) -> Powerset<I>
-where I: Iterator, I: Clone {
+where I: Iterator {
     Powerset::new(iter)
 }
 
+#[inline]
+pub fn powerset_range<I, R>(iter: I, range: R) -> PowersetRange<I>
+where I: Iterator, R: RangeBounds<usize> {
+    PowersetRange::new(iter, range)
+}
+
 pub trait Powersetable: Iterator
-where Self: Sized + Clone {
+where Self: Sized {
     #[inline]
     fn powerset(self) -> Powerset<Self> {
         Powerset::new(self)
     }
+
+    #[inline]
+    fn powerset_range<R>(self, range: R) -> PowersetRange<Self>
+    where R: RangeBounds<usize> {
+        PowersetRange::new(self, range)
+    }
 }
 
-impl<I> Powersetable for I where I: Iterator + Clone {}
+impl<I> Powersetable for I where I: Iterator {}
@@ -7,6 +7,8 @@ where F: Iterator {
     first_current: Option<F::Item>,
     second: S,
     second_clone: S,
+    // row that is consumed from the back, see DoubleEndedIterator
+    back: Option<(F::Item, S)>,
 }
 
 impl<F, S> Product<F, S>
@@ -18,6 +20,7 @@ where F: Iterator, S: Clone {
             first_current: None,
             second_clone: second.clone(),
             second,
+            back: None,
         }
     }
 }
@@ -31,74 +34,225 @@ where F: Iterator, F: Clone, F::Item: Clone, S: Clone {
             first_current: self.first_current.clone(),
             second: self.second.clone(),
             second_clone: self.second_clone.clone(),
+            back: self.back.clone(),
         }
     }
 }
 
+#[inline]
+fn exact_len((lower, upper): (usize, Option<usize>)) -> Option<usize> {
+    if upper == Some(lower) {
+        Some(lower)
+    } else {
+        None
+    }
+}
+
+impl<F, S> Product<F, S>
+where F: Iterator, F::Item: Clone, S: Iterator, S: Clone {
+    #[inline]
+    fn next_back_row(&mut self) -> Option<(F::Item, S::Item)> {
+        let (first_value, second) = self.back.as_mut()?;
+
+        match second.next() {
+            Some(second_value) => Some((first_value.clone(), second_value)),
+            None => {
+                self.back = None;
+                None
+            }
+        }
+    }
+
+    fn step_nth(&mut self, n: usize) -> Option<(F::Item, S::Item)> {
+        for _ in 0..n {
+            self.next()?;
+        }
+
+        self.next()
+    }
+}
+
 impl<F, S> Iterator for Product<F, S>
 where F: Iterator, F::Item: Clone, S: Iterator, S: Clone {
     type Item = (F::Item, S::Item);
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (first_lower, first_upper) = self.first.size_hint();
-        let (second_lower, second_upper) = self.second.size_hint();
-
-        if self.first_current.is_some() {
-            let (second_clone_lower, second_clone_upper) = self.second_clone.size_hint();
-            let lower = second_lower.saturating_add(first_lower.saturating_mul(second_clone_lower));
-            let upper = match (first_upper, second_upper, second_clone_upper) {
-                (Some(first_upper), Some(second_upper), Some(second_clone_upper)) => {
-                    first_upper
-                        .checked_mul(second_clone_upper)
-                        .and_then(|val| val.checked_add(second_upper))
-                },
-                _ => None,
-            };
-            (lower, upper)
+        let (second_clone_lower, second_clone_upper) = self.second_clone.size_hint();
+        let (second_lower, second_upper) = if self.first_current.is_some() {
+            self.second.size_hint()
         } else {
-            let lower = first_lower.saturating_mul(second_lower);
-            let upper = match (first_upper, second_upper) {
-                (Some(first_upper), Some(second_upper)) =>
-                    first_upper.checked_mul(second_upper),
-                _ => None,
-            };
-            (lower, upper)
-        }
+            (0, Some(0))
+        };
+        let (back_lower, back_upper) = match &self.back {
+            Some((_, back)) => back.size_hint(),
+            None => (0, Some(0)),
+        };
+
+        let lower = first_lower
+            .saturating_mul(second_clone_lower)
+            .saturating_add(second_lower)
+            .saturating_add(back_lower);
+        let upper = match (first_upper, second_clone_upper, second_upper, back_upper) {
+            (Some(first_upper), Some(second_clone_upper), Some(second_upper), Some(back_upper)) => {
+                first_upper
+                    .checked_mul(second_clone_upper)
+                    .and_then(|val| val.checked_add(second_upper))
+                    .and_then(|val| val.checked_add(back_upper))
+            },
+            _ => None,
+        };
+
+        (lower, upper)
     }
 
     fn count(self) -> usize {
         let first_count = self.first.count();
-        let second_count = self.second.count();
-
-        if self.first_current.is_some() {
-            let second_clone_count = self.second_clone.count();
-
-            first_count
-                .checked_mul(second_clone_count)
-                .and_then(|val| val.checked_add(second_count))
+        let second_clone_count = self.second_clone.count();
+        let second_count = if self.first_current.is_some() {
+            self.second.count()
         } else {
-            first_count.checked_mul(second_count)
-        }.expect("usize overflow")
+            0
+        };
+        let back_count = match self.back {
+            Some((_, back)) => back.count(),
+            None => 0,
+        };
+
+        first_count
+            .checked_mul(second_clone_count)
+            .and_then(|val| val.checked_add(second_count))
+            .and_then(|val| val.checked_add(back_count))
+            .expect("usize overflow")
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        let second_value = if let Some(second_value) = self.second.next() {
-            second_value
-        } else {
-            self.second = self.second_clone.clone();
+        if let Some(first_value) = &self.first_current {
             if let Some(second_value) = self.second.next() {
+                return Some((first_value.clone(), second_value));
+            }
+            self.first_current = None;
+        }
+
+        let Some(first_value) = self.first.next() else {
+            // only the row started from the back is left
+            return self.next_back_row();
+        };
+
+        self.second = self.second_clone.clone();
+        let second_value = self.second.next()?;
+        self.first_current = Some(first_value.clone());
+
+        Some((first_value, second_value))
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if self.first_current.is_some() {
+            let Some(len) = exact_len(self.second.size_hint()) else {
+                return self.step_nth(n);
+            };
+
+            if n < len {
+                let second_value = self.second.nth(n)?;
+                return self.first_current.clone().map(|first_value| (first_value, second_value));
+            }
+
+            n -= len;
+            self.first_current = None;
+        }
+
+        let (Some(rows), Some(len)) = (exact_len(self.first.size_hint()), exact_len(self.second_clone.size_hint())) else {
+            return self.step_nth(n);
+        };
+
+        // jump directly to the right row and column
+        if len > 0 && n / len < rows {
+            let first_value = self.first.nth(n / len)?;
+            self.second = self.second_clone.clone();
+            let second_value = self.second.nth(n % len)?;
+            self.first_current = Some(first_value.clone());
+
+            return Some((first_value, second_value));
+        }
+
+        n -= rows * len;
+        if rows > 0 {
+            self.first.nth(rows - 1);
+        }
+
+        let (first_value, second) = self.back.as_mut()?;
+        match second.nth(n) {
+            Some(second_value) => Some((first_value.clone(), second_value)),
+            None => {
+                self.back = None;
+                None
+            }
+        }
+    }
+}
+
+impl<F, S> DoubleEndedIterator for Product<F, S>
+where F: DoubleEndedIterator + ExactSizeIterator, F::Item: Clone,
+      S: DoubleEndedIterator + ExactSizeIterator, S: Clone {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some((first_value, second)) = &mut self.back {
+            if let Some(second_value) = second.next_back() {
+                return Some((first_value.clone(), second_value));
+            }
+            self.back = None;
+        }
+
+        let Some(first_value) = self.first.next_back() else {
+            // only the row started from the front is left
+            let first_value = self.first_current.clone()?;
+            let Some(second_value) = self.second.next_back() else {
                 self.first_current = None;
-                second_value
-            } else {
                 return None;
-            }
+            };
+            return Some((first_value, second_value));
         };
 
-        if self.first_current.is_none() {
-            self.first_current = self.first.next();
+        let mut second = self.second_clone.clone();
+        let second_value = second.next_back()?;
+        self.back = Some((first_value.clone(), second));
+
+        Some((first_value, second_value))
+    }
+
+    fn nth_back(&mut self, mut n: usize) -> Option<Self::Item> {
+        if let Some((first_value, second)) = &mut self.back {
+            let len = second.len();
+
+            if n < len {
+                let second_value = second.nth_back(n)?;
+                return Some((first_value.clone(), second_value));
+            }
+
+            n -= len;
+            self.back = None;
         }
 
-        let Some(first_value) = self.first_current.clone() else {
+        let rows = self.first.len();
+        let len = self.second_clone.len();
+
+        // jump directly to the right row and column
+        if len > 0 && n / len < rows {
+            let first_value = self.first.nth_back(n / len)?;
+            let mut second = self.second_clone.clone();
+            let second_value = second.nth_back(n % len)?;
+            self.back = Some((first_value.clone(), second));
+
+            return Some((first_value, second_value));
+        }
+
+        n -= rows * len;
+        if rows > 0 {
+            self.first.nth_back(rows - 1);
+        }
+
+        let first_value = self.first_current.clone()?;
+        let Some(second_value) = self.second.nth_back(n) else {
+            self.first_current = None;
             return None;
         };
 
@@ -106,14 +260,75 @@ where F: Iterator, F::Item: Clone, S: Iterator, S: Clone {
     }
 }
 
+// Like count(), len() panics when the product doesn't fit into usize.
+impl<F, S> ExactSizeIterator for Product<F, S>
+where F: ExactSizeIterator, F::Item: Clone, S: ExactSizeIterator, S: Clone {}
+
 #[inline]
 pub fn product<F, S>(first: F, second: S) -> Product<F, S>
 where F: Iterator, F::Item: Clone, S: Clone {
     Product::new(first, second)
 }
 
+// Flat tuples of the product of any number of iterables, e.g.
+// iproduct!(a, b, c) yields (a, b, c) for every combination.
+#[macro_export]
+macro_rules! iproduct {
+    (@build $iter:expr, ($($pat:tt)*), ($($val:tt)*),) => {
+        $iter.map(|$($pat)*| ($($val)*))
+    };
+    (@build $iter:expr, ($($pat:tt)*), ($($val:tt)*), $next:expr, $($rest:expr,)*) => {
+        $crate::iproduct!(
+            @build $crate::Product::new($iter, ::core::iter::IntoIterator::into_iter($next)),
+            (($($pat)*, value)),
+            ($($val)* value,),
+            $($rest,)*
+        )
+    };
+    () => {
+        ::core::iter::once(())
+    };
+    ($first:expr $(, $rest:expr)* $(,)?) => {
+        $crate::iproduct!(@build ::core::iter::IntoIterator::into_iter($first), (value), (value,), $($rest,)*)
+    };
+}
+
+// Indices into each of the inputs of the rank-th item of their product, in
+// the order Product and MultiProduct yield them (last input fastest).
+pub fn product_unrank(lengths: &[usize], mut rank: usize) -> Option<Vec<usize>> {
+    let mut indices = vec![0; lengths.len()];
+
+    for (index, &len) in indices.iter_mut().zip(lengths).rev() {
+        if len == 0 {
+            return None;
+        }
+        *index = rank % len;
+        rank /= len;
+    }
+
+    if rank != 0 {
+        return None;
+    }
+
+    Some(indices)
+}
+
+pub fn product_rank(lengths: &[usize], indices: &[usize]) -> Option<usize> {
+    if lengths.len() != indices.len() {
+        return None;
+    }
+
+    lengths.iter().zip(indices).try_fold(0usize, |rank, (&len, &index)| {
+        if index >= len {
+            return None;
+        }
+        rank.checked_mul(len)?.checked_add(index)
+    })
+}
+
 // The following are generated with make_products.py
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity, deprecated)]
 pub trait Productable: Iterator + Sized
 where Self::Item: Clone {
     #[inline]
@@ -123,6 +338,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product3<I2, I3>(self, i2: I2, i3: I3) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item)>
     where I2: Iterator, I3: Iterator,
           I2: Clone, I3: Clone,
@@ -131,6 +347,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product4<I2, I3, I4>(self, i2: I2, i3: I3, i4: I4) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator,
           I2: Clone, I3: Clone, I4: Clone,
@@ -139,6 +356,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product5<I2, I3, I4, I5>(self, i2: I2, i3: I3, i4: I4, i5: I5) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone,
@@ -147,6 +365,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product6<I2, I3, I4, I5, I6>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone,
@@ -155,6 +374,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product7<I2, I3, I4, I5, I6, I7>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone,
@@ -163,6 +383,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product8<I2, I3, I4, I5, I6, I7, I8>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone,
@@ -171,6 +392,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product9<I2, I3, I4, I5, I6, I7, I8, I9>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone,
@@ -179,6 +401,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product10<I2, I3, I4, I5, I6, I7, I8, I9, I10>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone,
@@ -187,6 +410,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product11<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone,
@@ -195,6 +419,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product12<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone,
@@ -203,6 +428,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product13<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone,
@@ -211,6 +437,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product14<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone, I14: Clone,
@@ -219,6 +446,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product15<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone, I14: Clone, I15: Clone,
@@ -227,6 +455,7 @@ where Self::Item: Clone {
     }
 
     #[inline]
+    #[deprecated(note = "use iproduct!")]
     fn product16<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15, I16>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15, i16: I16) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item, I16::Item)>
     where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator, I16: Iterator,
           I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone, I14: Clone, I15: Clone, I16: Clone,
@@ -238,6 +467,8 @@ where Self::Item: Clone {
 impl<I> Productable for I where I: Iterator + Sized, I::Item: Clone {}
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product3<I1, I2, I3>(i1: I1, i2: I2, i3: I3) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator,
       I2: Clone, I3: Clone,
@@ -246,6 +477,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator,
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product4<I1, I2, I3, I4>(i1: I1, i2: I2, i3: I3, i4: I4) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator,
       I2: Clone, I3: Clone, I4: Clone,
@@ -254,6 +487,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator,
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product5<I1, I2, I3, I4, I5>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone,
@@ -262,6 +497,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator,
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product6<I1, I2, I3, I4, I5, I6>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone,
@@ -270,6 +507,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product7<I1, I2, I3, I4, I5, I6, I7>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone,
@@ -278,6 +517,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product8<I1, I2, I3, I4, I5, I6, I7, I8>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone,
@@ -286,6 +527,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product9<I1, I2, I3, I4, I5, I6, I7, I8, I9>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone,
@@ -294,6 +537,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product10<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone,
@@ -302,6 +547,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product11<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone,
@@ -310,6 +557,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product12<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone,
@@ -318,6 +567,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product13<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone,
@@ -326,6 +577,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product14<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone, I14: Clone,
@@ -334,6 +587,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product15<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone, I14: Clone, I15: Clone,
@@ -342,6 +597,8 @@ where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6:
 }
 
 #[inline]
+#[deprecated(note = "use iproduct!")]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn product16<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15, I16>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15, i16: I16) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item, I16::Item)>
 where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator, I16: Iterator,
       I2: Clone, I3: Clone, I4: Clone, I5: Clone, I6: Clone, I7: Clone, I8: Clone, I9: Clone, I10: Clone, I11: Clone, I12: Clone, I13: Clone, I14: Clone, I15: Clone, I16: Clone,
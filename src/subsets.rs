@@ -0,0 +1,214 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubsetDelta<T> {
+    Added(T),
+    Removed(T),
+}
+
+impl<T> SubsetDelta<T> {
+    #[inline]
+    pub fn value(self) -> T {
+        match self {
+            Self::Added(value) | Self::Removed(value) => value,
+        }
+    }
+
+    #[inline]
+    pub fn is_added(&self) -> bool {
+        matches!(self, Self::Added(_))
+    }
+
+    #[inline]
+    pub fn is_removed(&self) -> bool {
+        matches!(self, Self::Removed(_))
+    }
+}
+
+// Walks all subsets in binary reflected Gray code order, starting with the
+// empty set. Every step adds or removes exactly one item, which is what gets
+// yielded. The current subset is available as a bitmask via mask().
+#[derive(Debug, Clone)]
+pub struct GrayCodePowerset<T> {
+    data: Vec<T>,
+    mask: u128,
+    counter: u128,
+    finished: bool,
+}
+
+impl<T> GrayCodePowerset<T> {
+    #[inline]
+    pub fn new(data: Vec<T>) -> Self {
+        assert!(data.len() <= 128, "at most 128 items are supported");
+
+        Self {
+            finished: data.is_empty(),
+            data,
+            mask: 0,
+            counter: 0,
+        }
+    }
+
+    #[inline]
+    pub fn mask(&self) -> u128 {
+        self.mask
+    }
+
+    pub fn subset(&self) -> Vec<&T> {
+        self.data.iter().enumerate()
+            .filter(|(index, _)| self.mask & (1 << index) != 0)
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        if self.finished {
+            return Some(0);
+        }
+
+        // 2^n - 1 deltas in total
+        let total = 1u128.checked_shl(self.data.len() as u32).map_or(u128::MAX, |total| total - 1);
+        usize::try_from(total - self.counter).ok()
+    }
+}
+
+impl<T> Iterator for GrayCodePowerset<T>
+where T: Clone {
+    type Item = SubsetDelta<T>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        self.counter += 1;
+        let index = self.counter.trailing_zeros() as usize;
+
+        if index >= self.data.len() {
+            self.finished = true;
+            return None;
+        }
+
+        let bit = 1u128 << index;
+        self.mask ^= bit;
+
+        if self.counter == u128::MAX {
+            self.finished = true;
+        }
+
+        let value = self.data[index].clone();
+
+        if self.mask & bit != 0 {
+            Some(SubsetDelta::Added(value))
+        } else {
+            Some(SubsetDelta::Removed(value))
+        }
+    }
+}
+
+macro_rules! subset_masks {
+    ($name:ident, $mask:ty, $func:ident) => {
+        // All bitmasks of n bits in increasing order, i.e. every subset of n
+        // items where bit i stands for item i.
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            front: $mask,
+            back: $mask,
+            finished: bool,
+        }
+
+        impl $name {
+            #[inline]
+            pub fn new(n: usize) -> Self {
+                assert!(n <= <$mask>::BITS as usize, "at most {} items are supported", <$mask>::BITS);
+
+                Self {
+                    front: 0,
+                    back: if n == <$mask>::BITS as usize { <$mask>::MAX } else { (1 << n) - 1 },
+                    finished: false,
+                }
+            }
+        }
+
+        impl Iterator for $name {
+            type Item = $mask;
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.finished {
+                    return (0, Some(0));
+                }
+
+                match usize::try_from(self.back - self.front).ok().and_then(|len| len.checked_add(1)) {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.finished {
+                    return None;
+                }
+
+                let mask = self.front;
+                if mask == self.back {
+                    self.finished = true;
+                } else {
+                    self.front += 1;
+                }
+
+                Some(mask)
+            }
+        }
+
+        impl DoubleEndedIterator for $name {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.finished {
+                    return None;
+                }
+
+                let mask = self.back;
+                if mask == self.front {
+                    self.finished = true;
+                } else {
+                    self.back -= 1;
+                }
+
+                Some(mask)
+            }
+        }
+
+        #[inline]
+        pub fn $func(n: usize) -> $name {
+            $name::new(n)
+        }
+    };
+}
+
+subset_masks!(SubsetMasks64, u64, subset_masks_u64);
+subset_masks!(SubsetMasks128, u128, subset_masks_u128);
+
+#[inline]
+pub fn gray_code_powerset<I>(iter: I) -> GrayCodePowerset<I::Item>
+where I: IntoIterator {
+    GrayCodePowerset::new(iter.into_iter().collect())
+}
+
+pub trait GrayCodePowersetable: Iterator
+where Self: Sized {
+    #[inline]
+    fn gray_code_powerset(self) -> GrayCodePowerset<Self::Item> {
+        GrayCodePowerset::new(self.collect())
+    }
+}
+
+impl<I> GrayCodePowersetable for I where I: Iterator {}
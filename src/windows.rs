@@ -0,0 +1,264 @@
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct ArrayWindows<I, const N: usize>
+where I: Iterator {
+    iter: I,
+    window: Option<[I::Item; N]>,
+    finished: bool,
+}
+
+impl<I, const N: usize> ArrayWindows<I, N>
+where I: Iterator {
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        const { assert!(N > 0, "window size must be non-zero") };
+
+        Self {
+            iter,
+            window: None,
+            finished: false,
+        }
+    }
+}
+
+impl<I, const N: usize> Clone for ArrayWindows<I, N>
+where I: Iterator, I: Clone, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            window: self.window.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+// Number of windows of the given size that are left when the first window
+// isn't filled yet.
+#[inline]
+fn pending_windows(size: usize, (lower, upper): (usize, Option<usize>)) -> (usize, Option<usize>) {
+    (lower.saturating_sub(size - 1), upper.map(|upper| upper.saturating_sub(size - 1)))
+}
+
+impl<I, const N: usize> Iterator for ArrayWindows<I, N>
+where I: Iterator, I::Item: Clone {
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+
+        if self.window.is_some() {
+            self.iter.size_hint()
+        } else {
+            pending_windows(N, self.iter.size_hint())
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.finished {
+            return 0;
+        }
+
+        if self.window.is_some() {
+            self.iter.count()
+        } else {
+            self.iter.count().saturating_sub(N - 1)
+        }
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // carry the previous N - 1 items over into the next window
+        if let Some(window) = &mut self.window {
+            let Some(item) = self.iter.next() else {
+                self.window = None;
+                self.finished = true;
+                return None;
+            };
+            window.rotate_left(1);
+            window[N - 1] = item;
+
+            return Some(window.clone());
+        }
+
+        let items: Vec<I::Item> = self.iter.by_ref().take(N).collect();
+        let Ok(window) = <[I::Item; N]>::try_from(items) else {
+            self.finished = true;
+            return None;
+        };
+        self.window = Some(window.clone());
+
+        Some(window)
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayWindows<I, N>
+where I: ExactSizeIterator, I::Item: Clone {}
+
+pub trait HomogeneousTuple: Sized {
+    type Item;
+    const SIZE: usize;
+
+    fn from_slice(slice: &[Self::Item]) -> Self
+    where Self::Item: Clone;
+}
+
+macro_rules! impl_homogeneous_tuple {
+    (@item $item:ident $index:tt) => { $item };
+    ($size:literal; $($index:tt)+) => {
+        impl<A> HomogeneousTuple for ($(impl_homogeneous_tuple!(@item A $index),)+) {
+            type Item = A;
+            const SIZE: usize = $size;
+
+            #[inline]
+            fn from_slice(slice: &[A]) -> Self
+            where A: Clone {
+                ($(slice[$index].clone(),)+)
+            }
+        }
+    };
+}
+
+impl_homogeneous_tuple!(2; 0 1);
+impl_homogeneous_tuple!(3; 0 1 2);
+impl_homogeneous_tuple!(4; 0 1 2 3);
+impl_homogeneous_tuple!(5; 0 1 2 3 4);
+impl_homogeneous_tuple!(6; 0 1 2 3 4 5);
+impl_homogeneous_tuple!(7; 0 1 2 3 4 5 6);
+impl_homogeneous_tuple!(8; 0 1 2 3 4 5 6 7);
+impl_homogeneous_tuple!(9; 0 1 2 3 4 5 6 7 8);
+impl_homogeneous_tuple!(10; 0 1 2 3 4 5 6 7 8 9);
+impl_homogeneous_tuple!(11; 0 1 2 3 4 5 6 7 8 9 10);
+impl_homogeneous_tuple!(12; 0 1 2 3 4 5 6 7 8 9 10 11);
+
+#[derive(Debug)]
+pub struct TupleWindows<I, T>
+where I: Iterator {
+    iter: I,
+    window: Vec<I::Item>,
+    finished: bool,
+    tuple: PhantomData<T>,
+}
+
+impl<I, T> TupleWindows<I, T>
+where I: Iterator, T: HomogeneousTuple<Item = I::Item> {
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            window: Vec::with_capacity(T::SIZE),
+            finished: false,
+            tuple: PhantomData,
+        }
+    }
+}
+
+impl<I, T> Clone for TupleWindows<I, T>
+where I: Iterator, I: Clone, I::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            window: self.window.clone(),
+            finished: self.finished,
+            tuple: PhantomData,
+        }
+    }
+}
+
+impl<I, T> Iterator for TupleWindows<I, T>
+where I: Iterator, I::Item: Clone, T: HomogeneousTuple<Item = I::Item> {
+    type Item = T;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+
+        if self.window.is_empty() {
+            pending_windows(T::SIZE, self.iter.size_hint())
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.finished {
+            return 0;
+        }
+
+        if self.window.is_empty() {
+            self.iter.count().saturating_sub(T::SIZE - 1)
+        } else {
+            self.iter.count()
+        }
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // carry the previous items over into the next window
+        if self.window.is_empty() {
+            self.window.extend(self.iter.by_ref().take(T::SIZE));
+
+            if self.window.len() < T::SIZE {
+                self.window.clear();
+                self.finished = true;
+                return None;
+            }
+        } else {
+            let Some(item) = self.iter.next() else {
+                self.window.clear();
+                self.finished = true;
+                return None;
+            };
+            self.window.rotate_left(1);
+            self.window[T::SIZE - 1] = item;
+        }
+
+        Some(T::from_slice(&self.window))
+    }
+}
+
+impl<I, T> ExactSizeIterator for TupleWindows<I, T>
+where I: ExactSizeIterator, I::Item: Clone, T: HomogeneousTuple<Item = I::Item> {}
+
+#[inline]
+pub fn array_windows<I, const N: usize>(iter: I) -> ArrayWindows<I, N>
+where I: Iterator {
+    ArrayWindows::new(iter)
+}
+
+#[inline]
+pub fn tuple_windows<I, T>(iter: I) -> TupleWindows<I, T>
+where I: Iterator, T: HomogeneousTuple<Item = I::Item> {
+    TupleWindows::new(iter)
+}
+
+pub trait Windowable: Iterator
+where Self: Sized {
+    #[inline]
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N> {
+        ArrayWindows::new(self)
+    }
+
+    #[inline]
+    fn tuple_windows<T>(self) -> TupleWindows<Self, T>
+    where T: HomogeneousTuple<Item = Self::Item> {
+        TupleWindows::new(self)
+    }
+}
+
+impl<I> Windowable for I where I: Iterator {}
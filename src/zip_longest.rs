@@ -1,13 +1,15 @@
+use std::iter::{Fuse, FusedIterator};
+
 #[derive(Debug)]
 pub struct ZipLongest<F, S> {
-    first: F,
-    second: S,
+    iter: ZipLongestEither<F, S>,
 }
 
-impl<F, S> ZipLongest<F, S> {
+impl<F, S> ZipLongest<F, S>
+where F: Iterator, S: Iterator {
     #[inline]
     pub fn new(first: F, second: S) -> Self {
-        Self { first, second }
+        Self { iter: ZipLongestEither::new(first, second) }
     }
 }
 
@@ -16,8 +18,7 @@ where F: Clone, S: Clone {
     #[inline]
     fn clone(&self) -> Self {
         Self {
-            first:  self.first.clone(),
-            second: self.second.clone(),
+            iter: self.iter.clone(),
         }
     }
 }
@@ -27,6 +28,171 @@ where F: Iterator, S: Iterator,
       F::Item: Default, S::Item: Default {
     type Item = (F::Item, S::Item);
 
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(EitherOrBoth::or_default)
+    }
+}
+
+impl<F, S> DoubleEndedIterator for ZipLongest<F, S>
+where F: DoubleEndedIterator + ExactSizeIterator, S: DoubleEndedIterator + ExactSizeIterator,
+      F::Item: Default, S::Item: Default {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(EitherOrBoth::or_default)
+    }
+}
+
+impl<F, S> ExactSizeIterator for ZipLongest<F, S>
+where F: ExactSizeIterator, S: ExactSizeIterator,
+      F::Item: Default, S::Item: Default {}
+
+impl<F, S> FusedIterator for ZipLongest<F, S>
+where F: Iterator, S: Iterator,
+      F::Item: Default, S::Item: Default {}
+
+#[inline]
+pub fn zip_longest<F, S>(first: F, second: S) -> ZipLongest<F, S>
+where F: Iterator, S: Iterator {
+    ZipLongest::new(first, second)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    Both(A, B),
+    Left(A),
+    Right(B),
+}
+
+impl<A, B> EitherOrBoth<A, B> {
+    #[inline]
+    pub fn has_left(&self) -> bool {
+        matches!(self, Self::Both(_, _) | Self::Left(_))
+    }
+
+    #[inline]
+    pub fn has_right(&self) -> bool {
+        matches!(self, Self::Both(_, _) | Self::Right(_))
+    }
+
+    #[inline]
+    pub fn is_both(&self) -> bool {
+        matches!(self, Self::Both(_, _))
+    }
+
+    #[inline]
+    pub fn left(self) -> Option<A> {
+        match self {
+            Self::Both(left, _) | Self::Left(left) => Some(left),
+            Self::Right(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn right(self) -> Option<B> {
+        match self {
+            Self::Both(_, right) | Self::Right(right) => Some(right),
+            Self::Left(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn both(self) -> Option<(A, B)> {
+        match self {
+            Self::Both(left, right) => Some((left, right)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_ref(&self) -> EitherOrBoth<&A, &B> {
+        match self {
+            Self::Both(left, right) => EitherOrBoth::Both(left, right),
+            Self::Left(left) => EitherOrBoth::Left(left),
+            Self::Right(right) => EitherOrBoth::Right(right),
+        }
+    }
+
+    #[inline]
+    pub fn or(self, left: A, right: B) -> (A, B) {
+        match self {
+            Self::Both(left, right) => (left, right),
+            Self::Left(left) => (left, right),
+            Self::Right(right) => (left, right),
+        }
+    }
+
+    #[inline]
+    pub fn or_else<L, R>(self, left: L, right: R) -> (A, B)
+    where L: FnOnce() -> A, R: FnOnce() -> B {
+        match self {
+            Self::Both(left, right) => (left, right),
+            Self::Left(left) => (left, right()),
+            Self::Right(right) => (left(), right),
+        }
+    }
+
+    #[inline]
+    pub fn or_default(self) -> (A, B)
+    where A: Default, B: Default {
+        self.or_else(A::default, B::default)
+    }
+}
+
+impl<T> EitherOrBoth<T, T> {
+    #[inline]
+    pub fn reduce<F>(self, f: F) -> T
+    where F: FnOnce(T, T) -> T {
+        match self {
+            Self::Both(left, right) => f(left, right),
+            Self::Left(value) | Self::Right(value) => value,
+        }
+    }
+}
+
+// Both sides are fused, so an exhausted side is never polled again.
+#[derive(Debug)]
+pub struct ZipLongestEither<F, S> {
+    first: Fuse<F>,
+    second: Fuse<S>,
+}
+
+impl<F, S> ZipLongestEither<F, S>
+where F: Iterator, S: Iterator {
+    #[inline]
+    pub fn new(first: F, second: S) -> Self {
+        Self {
+            first: first.fuse(),
+            second: second.fuse(),
+        }
+    }
+}
+
+impl<F, S> Clone for ZipLongestEither<F, S>
+where F: Clone, S: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            first:  self.first.clone(),
+            second: self.second.clone(),
+        }
+    }
+}
+
+impl<F, S> Iterator for ZipLongestEither<F, S>
+where F: Iterator, S: Iterator {
+    type Item = EitherOrBoth<F::Item, S::Item>;
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (first_lower, first_upper) = self.first.size_hint();
         let (second_lower, second_upper) = self.second.size_hint();
@@ -46,24 +212,433 @@ where F: Iterator, S: Iterator,
 
     fn next(&mut self) -> Option<Self::Item> {
         match (self.first.next(), self.second.next()) {
-            (Some(first), Some(second)) => Some((first, second)),
-            (Some(first), None) => Some((first, S::Item::default())),
-            (None, Some(second)) => Some((F::Item::default(), second)),
+            (Some(first), Some(second)) => Some(EitherOrBoth::Both(first, second)),
+            (Some(first), None) => Some(EitherOrBoth::Left(first)),
+            (None, Some(second)) => Some(EitherOrBoth::Right(second)),
             (None, None) => None,
         }
     }
 }
 
+impl<F, S> DoubleEndedIterator for ZipLongestEither<F, S>
+where F: DoubleEndedIterator + ExactSizeIterator, S: DoubleEndedIterator + ExactSizeIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // the shorter side is padded at the back
+        let first_len = self.first.len();
+        let second_len = self.second.len();
+
+        if first_len > second_len {
+            self.first.next_back().map(EitherOrBoth::Left)
+        } else if first_len < second_len {
+            self.second.next_back().map(EitherOrBoth::Right)
+        } else {
+            match (self.first.next_back(), self.second.next_back()) {
+                (Some(first), Some(second)) => Some(EitherOrBoth::Both(first, second)),
+                _ => None,
+            }
+        }
+    }
+}
+
+impl<F, S> ExactSizeIterator for ZipLongestEither<F, S>
+where F: ExactSizeIterator, S: ExactSizeIterator {}
+
+impl<F, S> FusedIterator for ZipLongestEither<F, S>
+where F: Iterator, S: Iterator {}
+
 #[inline]
-pub fn zip_longest<F, S>(first: F, second: S) -> ZipLongest<F, S> {
-    ZipLongest::new(first, second)
+pub fn zip_longest_either<F, S>(first: F, second: S) -> ZipLongestEither<F, S>
+where F: Iterator, S: Iterator {
+    ZipLongestEither::new(first, second)
+}
+
+pub struct ZipLongestFill<F, S>
+where F: Iterator, S: Iterator {
+    iter: ZipLongestEither<F, S>,
+    first_fill: F::Item,
+    second_fill: S::Item,
+}
+
+impl<F, S> ZipLongestFill<F, S>
+where F: Iterator, S: Iterator {
+    #[inline]
+    pub fn new(first: F, second: S, first_fill: F::Item, second_fill: S::Item) -> Self {
+        Self {
+            iter: ZipLongestEither::new(first, second),
+            first_fill,
+            second_fill,
+        }
+    }
+}
+
+impl<F, S> Clone for ZipLongestFill<F, S>
+where F: Iterator, S: Iterator, F: Clone, S: Clone, F::Item: Clone, S::Item: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            first_fill: self.first_fill.clone(),
+            second_fill: self.second_fill.clone(),
+        }
+    }
+}
+
+impl<F, S> Iterator for ZipLongestFill<F, S>
+where F: Iterator, S: Iterator, F::Item: Clone, S::Item: Clone {
+    type Item = (F::Item, S::Item);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        Some(item.or_else(|| self.first_fill.clone(), || self.second_fill.clone()))
+    }
+}
+
+impl<F, S> DoubleEndedIterator for ZipLongestFill<F, S>
+where F: DoubleEndedIterator + ExactSizeIterator, S: DoubleEndedIterator + ExactSizeIterator,
+      F::Item: Clone, S::Item: Clone {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+
+        Some(item.or_else(|| self.first_fill.clone(), || self.second_fill.clone()))
+    }
+}
+
+impl<F, S> ExactSizeIterator for ZipLongestFill<F, S>
+where F: ExactSizeIterator, S: ExactSizeIterator, F::Item: Clone, S::Item: Clone {}
+
+impl<F, S> FusedIterator for ZipLongestFill<F, S>
+where F: Iterator, S: Iterator, F::Item: Clone, S::Item: Clone {}
+
+#[derive(Debug)]
+pub struct ZipLongestWith<F, S, FF, SF> {
+    iter: ZipLongestEither<F, S>,
+    first_fill: FF,
+    second_fill: SF,
+}
+
+impl<F, S, FF, SF> ZipLongestWith<F, S, FF, SF>
+where F: Iterator, S: Iterator, FF: FnMut() -> F::Item, SF: FnMut() -> S::Item {
+    #[inline]
+    pub fn new(first: F, second: S, first_fill: FF, second_fill: SF) -> Self {
+        Self {
+            iter: ZipLongestEither::new(first, second),
+            first_fill,
+            second_fill,
+        }
+    }
+}
+
+impl<F, S, FF, SF> Clone for ZipLongestWith<F, S, FF, SF>
+where F: Clone, S: Clone, FF: Clone, SF: Clone {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            first_fill: self.first_fill.clone(),
+            second_fill: self.second_fill.clone(),
+        }
+    }
+}
+
+impl<F, S, FF, SF> Iterator for ZipLongestWith<F, S, FF, SF>
+where F: Iterator, S: Iterator, FF: FnMut() -> F::Item, SF: FnMut() -> S::Item {
+    type Item = (F::Item, S::Item);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        Some(item.or_else(&mut self.first_fill, &mut self.second_fill))
+    }
+}
+
+impl<F, S, FF, SF> DoubleEndedIterator for ZipLongestWith<F, S, FF, SF>
+where F: DoubleEndedIterator + ExactSizeIterator, S: DoubleEndedIterator + ExactSizeIterator,
+      FF: FnMut() -> F::Item, SF: FnMut() -> S::Item {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+
+        Some(item.or_else(&mut self.first_fill, &mut self.second_fill))
+    }
+}
+
+impl<F, S, FF, SF> ExactSizeIterator for ZipLongestWith<F, S, FF, SF>
+where F: ExactSizeIterator, S: ExactSizeIterator, FF: FnMut() -> F::Item, SF: FnMut() -> S::Item {}
+
+impl<F, S, FF, SF> FusedIterator for ZipLongestWith<F, S, FF, SF>
+where F: Iterator, S: Iterator, FF: FnMut() -> F::Item, SF: FnMut() -> S::Item {}
+
+#[inline]
+pub fn zip_longest_fill<F, S>(first: F, second: S, first_fill: F::Item, second_fill: S::Item) -> ZipLongestFill<F, S>
+where F: Iterator, S: Iterator {
+    ZipLongestFill::new(first, second, first_fill, second_fill)
+}
+
+#[inline]
+pub fn zip_longest_with<F, S, FF, SF>(first: F, second: S, first_fill: FF, second_fill: SF) -> ZipLongestWith<F, S, FF, SF>
+where F: Iterator, S: Iterator, FF: FnMut() -> F::Item, SF: FnMut() -> S::Item {
+    ZipLongestWith::new(first, second, first_fill, second_fill)
 }
 
+// The following are generated with make_zip_longest.py
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub trait ZippableLongest: Iterator + Sized {
     #[inline]
     fn zip_longest<Iter: Iterator>(self, iter: Iter) -> ZipLongest<Self, Iter> {
         ZipLongest::new(self, iter)
     }
+
+    #[inline]
+    fn zip_longest_either<Iter: Iterator>(self, iter: Iter) -> ZipLongestEither<Self, Iter> {
+        ZipLongestEither::new(self, iter)
+    }
+
+    #[inline]
+    fn zip_longest_fill<Iter: Iterator>(self, iter: Iter, first_fill: Self::Item, second_fill: Iter::Item) -> ZipLongestFill<Self, Iter> {
+        ZipLongestFill::new(self, iter, first_fill, second_fill)
+    }
+
+    #[inline]
+    fn zip_longest_with<Iter, FF, SF>(self, iter: Iter, first_fill: FF, second_fill: SF) -> ZipLongestWith<Self, Iter, FF, SF>
+    where Iter: Iterator, FF: FnMut() -> Self::Item, SF: FnMut() -> Iter::Item {
+        ZipLongestWith::new(self, iter, first_fill, second_fill)
+    }
+
+    #[inline]
+    fn zip_longest3<I2, I3>(self, i2: I2, i3: I3) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item)>
+    where I2: Iterator, I3: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default {
+        zip_longest3(self, i2, i3)
+    }
+
+    #[inline]
+    fn zip_longest4<I2, I3, I4>(self, i2: I2, i3: I3, i4: I4) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default {
+        zip_longest4(self, i2, i3, i4)
+    }
+
+    #[inline]
+    fn zip_longest5<I2, I3, I4, I5>(self, i2: I2, i3: I3, i4: I4, i5: I5) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default {
+        zip_longest5(self, i2, i3, i4, i5)
+    }
+
+    #[inline]
+    fn zip_longest6<I2, I3, I4, I5, I6>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default {
+        zip_longest6(self, i2, i3, i4, i5, i6)
+    }
+
+    #[inline]
+    fn zip_longest7<I2, I3, I4, I5, I6, I7>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default {
+        zip_longest7(self, i2, i3, i4, i5, i6, i7)
+    }
+
+    #[inline]
+    fn zip_longest8<I2, I3, I4, I5, I6, I7, I8>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default {
+        zip_longest8(self, i2, i3, i4, i5, i6, i7, i8)
+    }
+
+    #[inline]
+    fn zip_longest9<I2, I3, I4, I5, I6, I7, I8, I9>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default {
+        zip_longest9(self, i2, i3, i4, i5, i6, i7, i8, i9)
+    }
+
+    #[inline]
+    fn zip_longest10<I2, I3, I4, I5, I6, I7, I8, I9, I10>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default {
+        zip_longest10(self, i2, i3, i4, i5, i6, i7, i8, i9, i10)
+    }
+
+    #[inline]
+    fn zip_longest11<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default {
+        zip_longest11(self, i2, i3, i4, i5, i6, i7, i8, i9, i10, i11)
+    }
+
+    #[inline]
+    fn zip_longest12<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default {
+        zip_longest12(self, i2, i3, i4, i5, i6, i7, i8, i9, i10, i11, i12)
+    }
+
+    #[inline]
+    fn zip_longest13<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default {
+        zip_longest13(self, i2, i3, i4, i5, i6, i7, i8, i9, i10, i11, i12, i13)
+    }
+
+    #[inline]
+    fn zip_longest14<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default, I14::Item: Default {
+        zip_longest14(self, i2, i3, i4, i5, i6, i7, i8, i9, i10, i11, i12, i13, i14)
+    }
+
+    #[inline]
+    fn zip_longest15<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default, I14::Item: Default, I15::Item: Default {
+        zip_longest15(self, i2, i3, i4, i5, i6, i7, i8, i9, i10, i11, i12, i13, i14, i15)
+    }
+
+    #[inline]
+    fn zip_longest16<I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15, I16>(self, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15, i16: I16) -> impl Iterator<Item = (Self::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item, I16::Item)>
+    where I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator, I16: Iterator,
+          Self::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default, I14::Item: Default, I15::Item: Default, I16::Item: Default {
+        zip_longest16(self, i2, i3, i4, i5, i6, i7, i8, i9, i10, i11, i12, i13, i14, i15, i16)
+    }
+
 }
 
 impl<I> ZippableLongest for I where I: Iterator {}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest3<I1, I2, I3>(i1: I1, i2: I2, i3: I3) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default {
+    ZipLongest::new(ZipLongest::new(i1, i2), i3).map(|((v1, v2), v3)| (v1, v2, v3))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest4<I1, I2, I3, I4>(i1: I1, i2: I2, i3: I3, i4: I4) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4).map(|(((v1, v2), v3), v4)| (v1, v2, v3, v4))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest5<I1, I2, I3, I4, I5>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5).map(|((((v1, v2), v3), v4), v5)| (v1, v2, v3, v4, v5))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest6<I1, I2, I3, I4, I5, I6>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6).map(|(((((v1, v2), v3), v4), v5), v6)| (v1, v2, v3, v4, v5, v6))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest7<I1, I2, I3, I4, I5, I6, I7>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7).map(|((((((v1, v2), v3), v4), v5), v6), v7)| (v1, v2, v3, v4, v5, v6, v7))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest8<I1, I2, I3, I4, I5, I6, I7, I8>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8).map(|(((((((v1, v2), v3), v4), v5), v6), v7), v8)| (v1, v2, v3, v4, v5, v6, v7, v8))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest9<I1, I2, I3, I4, I5, I6, I7, I8, I9>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9).map(|((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9)| (v1, v2, v3, v4, v5, v6, v7, v8, v9))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest10<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9), i10).map(|(((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9), v10)| (v1, v2, v3, v4, v5, v6, v7, v8, v9, v10))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest11<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9), i10), i11).map(|((((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9), v10), v11)| (v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest12<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9), i10), i11), i12).map(|(((((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9), v10), v11), v12)| (v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest13<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9), i10), i11), i12), i13).map(|((((((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9), v10), v11), v12), v13)| (v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest14<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default, I14::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9), i10), i11), i12), i13), i14).map(|(((((((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9), v10), v11), v12), v13), v14)| (v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest15<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default, I14::Item: Default, I15::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9), i10), i11), i12), i13), i14), i15).map(|((((((((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9), v10), v11), v12), v13), v14), v15)| (v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14, v15))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn zip_longest16<I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15, I16>(i1: I1, i2: I2, i3: I3, i4: I4, i5: I5, i6: I6, i7: I7, i8: I8, i9: I9, i10: I10, i11: I11, i12: I12, i13: I13, i14: I14, i15: I15, i16: I16) -> impl Iterator<Item = (I1::Item, I2::Item, I3::Item, I4::Item, I5::Item, I6::Item, I7::Item, I8::Item, I9::Item, I10::Item, I11::Item, I12::Item, I13::Item, I14::Item, I15::Item, I16::Item)>
+where I1: Iterator, I2: Iterator, I3: Iterator, I4: Iterator, I5: Iterator, I6: Iterator, I7: Iterator, I8: Iterator, I9: Iterator, I10: Iterator, I11: Iterator, I12: Iterator, I13: Iterator, I14: Iterator, I15: Iterator, I16: Iterator,
+      I1::Item: Default, I2::Item: Default, I3::Item: Default, I4::Item: Default, I5::Item: Default, I6::Item: Default, I7::Item: Default, I8::Item: Default, I9::Item: Default, I10::Item: Default, I11::Item: Default, I12::Item: Default, I13::Item: Default, I14::Item: Default, I15::Item: Default, I16::Item: Default {
+    ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(ZipLongest::new(i1, i2), i3), i4), i5), i6), i7), i8), i9), i10), i11), i12), i13), i14), i15), i16).map(|(((((((((((((((v1, v2), v3), v4), v5), v6), v7), v8), v9), v10), v11), v12), v13), v14), v15), v16)| (v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14, v15, v16))
+}
@@ -0,0 +1,12 @@
+// Steps through iter, checking that size_hint() and count() agree with the
+// number of items that are actually left after every step.
+pub fn assert_exact_remaining<I>(mut iter: I, len: usize)
+where
+    I: Iterator + Clone,
+{
+    for remaining in (0..=len).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        assert_eq!(iter.clone().count(), remaining);
+        assert_eq!(iter.next().is_some(), remaining > 0);
+    }
+}
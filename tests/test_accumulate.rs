@@ -1,4 +1,4 @@
-use itertools::{Accumulatable, accumulate};
+use itertools::{Accumulatable, accumulate, accumulate_with, accumulate_with_initial};
 
 #[test]
 fn test_accumulate0() {
@@ -32,3 +32,63 @@ fn test_accumulate5() {
     assert_eq!(accumulate(values.into_iter()).count(), expected.len());
     assert_eq!(accumulate(values.into_iter()).size_hint(), expected.iter().size_hint());
 }
+
+#[test]
+fn test_accumulate_with0() {
+    let values   = [0; 0];
+    let expected = [0; 0];
+    let actual: Vec<_> = accumulate_with(values.into_iter(), i32::max).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(accumulate_with(values.into_iter(), i32::max).count(), expected.len());
+    assert_eq!(accumulate_with(values.into_iter(), i32::max).size_hint(), expected.iter().size_hint());
+}
+
+#[test]
+fn test_accumulate_with_max() {
+    let values   = [3, 4, 6, 2, 1, 9, 0, 7, 5, 8];
+    let expected = [3, 4, 6, 6, 6, 9, 9, 9, 9, 9];
+    let actual: Vec<_> = values.into_iter().accumulate_with(i32::max).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(accumulate_with(values.into_iter(), i32::max).count(), expected.len());
+    assert_eq!(accumulate_with(values.into_iter(), i32::max).size_hint(), expected.iter().size_hint());
+}
+
+#[test]
+fn test_accumulate_with_product() {
+    let values   = [1, 2, 3, 4, 5];
+    let expected = [1, 2, 6, 24, 120];
+    let actual: Vec<_> = accumulate_with(values.into_iter(), |a, b| a * b).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_accumulate_with_initial() {
+    let values   = [1, 2, 3];
+    let expected = [100, 101, 103, 106];
+    let actual: Vec<_> = values.into_iter().accumulate_with_initial(100, |a, b| a + b).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(accumulate_with_initial(values.into_iter(), 100, |a, b| a + b).count(), expected.len());
+    assert_eq!(accumulate_with_initial(values.into_iter(), 100, |a, b| a + b).size_hint(), expected.iter().size_hint());
+}
+
+#[test]
+fn test_accumulate_with_initial_empty() {
+    let values: [String; 0] = [];
+    let expected = ["seed".to_string()];
+    let actual: Vec<_> = accumulate_with_initial(values.into_iter(), "seed".to_string(), |a, b| a + &b).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_accumulate_with_initial_concat() {
+    let values   = ["b", "c", "d"].map(String::from);
+    let expected = ["a", "ab", "abc", "abcd"].map(String::from);
+    let actual: Vec<_> = values.into_iter().accumulate_with_initial("a".to_string(), |a, b| a + &b).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
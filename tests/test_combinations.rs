@@ -1,4 +1,11 @@
-use itertools::{Combinable, combinations};
+use itertools::{
+    Combinable, Combinations, IndexCombinations, SliceCombinable, SliceCombinations,
+    array_combinations, combination_rank, combination_unrank, combinations, combinations_indices,
+    next_combination, prev_combination, slice_combinations,
+};
+
+mod common;
+use common::assert_exact_remaining;
 
 #[test]
 fn test_combinations0() {
@@ -77,3 +84,268 @@ fn test_combinations11() {
 
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_combinations_next_slice() {
+    let values = "01234";
+    let expected = combinations(values.chars(), 3).collect::<Vec<_>>();
+    let mut iter = combinations(values.chars(), 3);
+    let mut actual = Vec::new();
+
+    while let Some(slice) = iter.next_slice() {
+        actual.push(slice.to_vec());
+    }
+
+    assert_eq!(expected, actual);
+    assert_eq!(actual.len(), 10);
+    assert_eq!(iter.next_slice(), None);
+}
+
+#[test]
+fn test_combinations_next_slice0() {
+    let mut iter = combinations("abc".chars(), 0);
+
+    assert_eq!(iter.next_slice(), Some(&[][..]));
+    assert_eq!(iter.next_slice(), None);
+
+    let mut iter = combinations("abc".chars(), 4);
+
+    assert_eq!(iter.next_slice(), None);
+}
+
+#[test]
+fn test_combinations_size_hint() {
+    let values = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+    for r in 0..=11 {
+        let len = combinations(values.into_iter(), r).collect::<Vec<_>>().len();
+        assert_exact_remaining(combinations(values.into_iter(), r), len);
+    }
+
+    assert_eq!(combinations(values.into_iter(), 3).size_hint(), (120, Some(120)));
+    assert_eq!(combinations(0..60, 5).len(), 5461512);
+}
+
+#[test]
+fn test_combinations_size_hint_overflow() {
+    let iter = combinations(0..usize::MAX, 3);
+
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn test_combination_rank() {
+    for n in 0..=7 {
+        for k in 0..=n + 1 {
+            let expected = combinations(0..n, k).collect::<Vec<_>>();
+
+            for (rank, combination) in expected.iter().enumerate() {
+                assert_eq!(combination_unrank(n, k, rank).as_ref(), Some(combination));
+                assert_eq!(combination_rank(n, combination), Some(rank));
+            }
+
+            assert_eq!(combination_unrank(n, k, expected.len()), None);
+        }
+    }
+
+    assert_eq!(combination_rank(5, &[1, 1]), None);
+    assert_eq!(combination_rank(5, &[2, 1]), None);
+    assert_eq!(combination_rank(5, &[1, 5]), None);
+    assert_eq!(combination_unrank(60, 5, 5461511), Some(vec![55, 56, 57, 58, 59]));
+}
+
+#[test]
+fn test_combination_rank_overflow() {
+    // there are more than usize::MAX 50-combinations of 0..100
+    let first = (0..50).collect::<Vec<_>>();
+    assert_eq!(combination_unrank(100, 50, 0), Some(first.clone()));
+    assert_eq!(combination_rank(100, &first), Some(0));
+
+    let mut second = first.clone();
+    second[49] = 50;
+    assert_eq!(combination_unrank(100, 50, 1), Some(second));
+
+    for rank in [2, 12345, usize::MAX / 3, usize::MAX] {
+        let combination = combination_unrank(100, 50, rank).unwrap();
+        assert_eq!(combination_rank(100, &combination), Some(rank));
+    }
+
+    let last = (50..100).collect::<Vec<_>>();
+    assert_eq!(combination_rank(100, &last), None);
+}
+
+#[test]
+fn test_next_combination() {
+    let expected = combinations(0..6, 3).collect::<Vec<_>>();
+    let mut indices = combination_unrank(6, 3, 7).unwrap();
+    let mut actual = vec![indices.clone()];
+
+    while next_combination(&mut indices, 6) {
+        actual.push(indices.clone());
+    }
+
+    assert_eq!(&expected[7..], &actual[..]);
+}
+
+#[test]
+fn test_prev_combination() {
+    let expected = combinations(0..6, 3).collect::<Vec<_>>();
+    let mut indices = combination_unrank(6, 3, 12).unwrap();
+    let mut actual = vec![indices.clone()];
+
+    while prev_combination(&mut indices, 6) {
+        actual.push(indices.clone());
+    }
+    actual.reverse();
+
+    assert_eq!(&expected[..=12], &actual[..]);
+
+    let mut indices: [usize; 0] = [];
+    assert!(!prev_combination(&mut indices, 3));
+}
+
+#[test]
+fn test_combinations_from_rank() {
+    let values = ['a', 'b', 'c', 'd', 'e', 'f'];
+    let expected = combinations(values.into_iter(), 3).collect::<Vec<_>>();
+
+    for rank in 0..=expected.len() {
+        let actual = Combinations::from_rank(values.into_iter(), 3, rank).collect::<Vec<_>>();
+        assert_eq!(&expected[rank..], &actual[..]);
+
+        let iter = Combinations::from_rank(values.into_iter(), 3, rank);
+        assert_eq!(iter.size_hint(), (expected.len() - rank, Some(expected.len() - rank)));
+    }
+
+    assert_eq!(Combinations::from_rank(values.into_iter(), 3, 21).next(), None);
+    assert_eq!(Combinations::from_rank(values.into_iter(), 0, 0).next(), Some(vec![]));
+
+    assert_eq!(
+        Combinations::from_rank(0..100, 50, 5).next(),
+        combinations(0..100, 50).nth(5)
+    );
+}
+
+#[test]
+fn test_combinations_indices() {
+    let expected = combinations(0..5, 3).collect::<Vec<_>>();
+    let actual = combinations_indices(5, 3).collect::<Vec<_>>();
+
+    assert_eq!(expected, actual);
+    assert_eq!(combinations_indices(5, 0).collect::<Vec<_>>(), [Vec::<usize>::new()]);
+    assert_eq!(combinations_indices(0, 0).count(), 1);
+    assert_eq!(combinations_indices(3, 4).count(), 0);
+
+    let mut iter = combinations_indices(5, 2);
+    assert_eq!(iter.next_slice(), Some(&[0, 1][..]));
+    assert_eq!(iter.next_slice(), Some(&[0, 2][..]));
+}
+
+#[test]
+fn test_combinations_indices_size_hint() {
+    for n in 0..=7 {
+        for k in 0..=n + 1 {
+            let total = combinations(0..n, k).count();
+
+            assert_exact_remaining(combinations_indices(n, k), total);
+        }
+    }
+
+    assert_eq!(combinations_indices(100, 50).size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn test_slice_combinations() {
+    let values = ["a", "b", "c", "d"];
+    let expected = ["ab", "ac", "ad", "bc", "bd", "cd"];
+    let actual = values.combinations(2).map(
+        |v| v.into_iter().copied().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    let actual = slice_combinations(&values[1..], 3).map(
+        |v| v.into_iter().copied().collect::<String>()
+    ).collect::<Vec<_>>();
+    assert_eq!(actual, ["bcd"]);
+    assert_eq!(values.combinations(3).count(), 4);
+    assert_eq!(values.combinations(5).count(), 0);
+}
+
+#[test]
+fn test_array_combinations() {
+    let values = "abcd";
+    let expected = combinations(values.chars(), 3).collect::<Vec<_>>();
+    let actual = values.chars().array_combinations::<3>().map(
+        |[a, b, c]| vec![a, b, c]
+    ).collect::<Vec<_>>();
+
+    assert_eq!(expected, actual);
+
+    let actual = array_combinations::<_, 2>(0..3).collect::<Vec<_>>();
+    assert_eq!(actual, [[0, 1], [0, 2], [1, 2]]);
+
+    assert_eq!((0..3).array_combinations::<0>().collect::<Vec<_>>(), [[0; 0]]);
+    assert_eq!((0..3).array_combinations::<4>().next(), None);
+}
+
+#[test]
+fn test_array_combinations_size_hint() {
+    for n in 0..=7 {
+        let total = combinations(0..n, 3).count();
+
+        assert_exact_remaining((0..n).array_combinations::<3>(), total);
+    }
+}
+
+#[test]
+fn test_index_combinations_from_rank() {
+    for n in 0..=6 {
+        for k in 0..=n + 1 {
+            let expected = combinations_indices(n, k).collect::<Vec<_>>();
+
+            for rank in 0..=expected.len() {
+                let actual = IndexCombinations::from_rank(n, k, rank).collect::<Vec<_>>();
+                assert_eq!(&expected[rank..], &actual[..]);
+                assert_eq!(combinations_indices(n, k).nth(rank).as_ref(), expected.get(rank));
+            }
+
+            // nth() continues from where the iterator is
+            let mut iter = combinations_indices(n, k);
+            let mut rank = 0;
+            for step in 0..4 {
+                assert_eq!(iter.nth(step).as_ref(), expected.get(rank + step));
+                rank += step + 1;
+            }
+            assert_eq!(iter.next().as_ref(), expected.get(rank));
+        }
+    }
+
+    let values = [1, 2, 3, 4, 5];
+    let expected = values.combinations(2).collect::<Vec<_>>();
+    let mut actual = Vec::new();
+    for start in (0..expected.len()).step_by(3) {
+        actual.extend(SliceCombinations::from_rank(&values, 2, start).take(3));
+    }
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_index_combinations_overflow() {
+    assert_eq!(IndexCombinations::from_rank(100, 50, 5).next(), combination_unrank(100, 50, 5));
+
+    let values = (0..100).collect::<Vec<_>>();
+    assert_eq!(
+        SliceCombinations::from_rank(&values, 50, 5).next().map(|v| v.into_iter().copied().collect()),
+        combination_unrank(100, 50, 5)
+    );
+
+    let mut iter = combinations_indices(100, 50);
+    assert_eq!(iter.nth(usize::MAX - 1), combination_unrank(100, 50, usize::MAX - 1));
+    assert_eq!(iter.next(), combination_unrank(100, 50, usize::MAX));
+
+    // past the ranks that fit into usize it keeps on stepping
+    let mut expected = iter.clone().next().unwrap();
+    assert!(next_combination(&mut expected, 100));
+    assert_eq!(iter.nth(1), Some(expected));
+}
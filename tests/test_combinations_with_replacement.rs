@@ -0,0 +1,62 @@
+use itertools::{CombinableWithReplacement, combinations_with_replacement};
+
+mod common;
+use common::assert_exact_remaining;
+
+#[test]
+fn test_combinations_with_replacement0() {
+    let values = "abc";
+    let expected: Vec<String> = vec!["".to_string()];
+    let actual = combinations_with_replacement(values.chars(), 0).map(
+        |v| v.iter().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_combinations_with_replacement_empty() {
+    let values = "";
+    let expected: Vec<String> = Vec::new();
+    let actual = combinations_with_replacement(values.chars(), 2).map(
+        |v| v.iter().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_combinations_with_replacement2() {
+    let values = "ABC";
+    let expected = ["AA", "AB", "AC", "BB", "BC", "CC"].map(String::from);
+    let actual = values.chars().combinations_with_replacement(2).map(
+        |v| v.iter().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(expected.as_slice(), actual.as_slice());
+}
+
+#[test]
+fn test_combinations_with_replacement3() {
+    let values = "ab";
+    let expected = ["aaa", "aab", "abb", "bbb"].map(String::from);
+    let actual = combinations_with_replacement(values.chars(), 3).map(
+        |v| v.iter().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(expected.as_slice(), actual.as_slice());
+}
+
+#[test]
+fn test_combinations_with_replacement_size_hint() {
+    let values = [1, 2, 3, 4, 5, 6];
+
+    for r in 0..=4 {
+        let len = combinations_with_replacement(values.into_iter(), r).collect::<Vec<_>>().len();
+
+        assert_exact_remaining(combinations_with_replacement(values.into_iter(), r), len);
+    }
+
+    // rolling three dice
+    assert_eq!(values.into_iter().combinations_with_replacement(3).count(), 56);
+}
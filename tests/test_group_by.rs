@@ -0,0 +1,76 @@
+use itertools::{Groupable, group_by};
+
+#[test]
+fn test_group_by_empty() {
+    let values = "";
+    let expected: Vec<(char, String)> = Vec::new();
+    let groups = group_by(values.chars(), |c| *c);
+    let actual: Vec<(char, String)> = groups.groups().map(
+        |(key, group)| (key, group.collect())
+    ).collect();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_group_by() {
+    let values = "aaabccdaa";
+    let expected = [('a', "aaa"), ('b', "b"), ('c', "cc"), ('d', "d"), ('a', "aa")]
+        .map(|(key, group)| (key, group.to_string()));
+    let groups = values.chars().group_by(|c| *c);
+    let actual: Vec<(char, String)> = (&groups).into_iter().map(
+        |(key, group)| (key, group.collect())
+    ).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_group_by_key() {
+    let values = [1, 3, 5, 2, 4, 7, 6];
+    let expected = vec![
+        (true,  vec![1, 3, 5]),
+        (false, vec![2, 4]),
+        (true,  vec![7]),
+        (false, vec![6]),
+    ];
+    let mut actual = Vec::new();
+
+    for (odd, group) in &values.into_iter().group_by(|value| value % 2 == 1) {
+        actual.push((odd, group.collect::<Vec<_>>()));
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_group_by_skip_groups() {
+    let values = "aaabccdaa";
+    let expected = ['a', 'b', 'c', 'd', 'a'];
+    let groups = values.chars().group_by(|c| *c);
+    let actual: Vec<char> = groups.groups().map(|(key, _)| key).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_group_by_partially_consumed() {
+    let values = "aaabbbc";
+    let groups = values.chars().group_by(|c| *c);
+    let mut iter = groups.groups();
+
+    let (key, mut first) = iter.next().unwrap();
+    assert_eq!(key, 'a');
+    assert_eq!(first.next(), Some('a'));
+
+    let (key, second) = iter.next().unwrap();
+    assert_eq!(key, 'b');
+    assert_eq!(first.next(), None);
+    assert_eq!(second.collect::<String>(), "bbb");
+
+    let (key, third) = iter.next().unwrap();
+    assert_eq!(key, 'c');
+    assert_eq!(third.collect::<String>(), "c");
+
+    assert!(iter.next().is_none());
+}
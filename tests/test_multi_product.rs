@@ -0,0 +1,149 @@
+use itertools::{
+    MultiProduct, MultiProductable, ProductRepeatable, iproduct, multi_product, product_repeat,
+};
+
+mod common;
+use common::assert_exact_remaining;
+
+#[test]
+fn test_multi_product0() {
+    let iters: Vec<std::ops::Range<u32>> = Vec::new();
+    let expected: Vec<Vec<u32>> = vec![vec![]];
+    let actual: Vec<Vec<u32>> = multi_product(iters.clone()).collect();
+
+    assert_eq!(expected, actual);
+    assert_eq!(multi_product(iters.clone()).count(), expected.len());
+    assert_eq!(multi_product(iters).size_hint(), (expected.len(), Some(expected.len())));
+}
+
+#[test]
+fn test_multi_product_empty_input() {
+    let iters = vec![0..2, 0..0, 0..3];
+    let expected: Vec<Vec<u32>> = Vec::new();
+    let actual: Vec<Vec<u32>> = multi_product(iters.clone()).collect();
+
+    assert_eq!(expected, actual);
+    assert_eq!(multi_product(iters.clone()).count(), expected.len());
+    assert_eq!(multi_product(iters).size_hint(), (expected.len(), Some(expected.len())));
+}
+
+#[test]
+fn test_multi_product3() {
+    let values = ["ab", "xyz", "01"];
+    let expected = [
+        "ax0", "ax1", "ay0", "ay1", "az0", "az1",
+        "bx0", "bx1", "by0", "by1", "bz0", "bz1",
+    ];
+    let actual: Vec<String> = values.iter().map(|value| value.chars()).multi_product().map(
+        |item| item.into_iter().collect()
+    ).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_multi_product_size_hint() {
+    let iters = vec![0..2, 0..3, 0..4];
+    let len = 2 * 3 * 4;
+
+    assert_exact_remaining(multi_product(iters.clone()), len);
+
+    assert_eq!(multi_product(iters).count(), len);
+}
+
+#[test]
+fn test_multi_product_next_slice() {
+    let mut iter = multi_product(vec![[1, 2].iter(), [3].iter()]);
+
+    assert_eq!(iter.next_slice(), Some(&[&1, &3][..]));
+    assert_eq!(iter.next_slice(), Some(&[&2, &3][..]));
+    assert_eq!(iter.next_slice(), None);
+}
+
+#[test]
+fn test_multi_product_in_struct() {
+    struct Grid {
+        cells: MultiProduct<std::ops::Range<i32>>,
+    }
+
+    let mut grid = Grid { cells: multi_product(vec![0..2, 0..2]) };
+
+    assert_eq!(grid.cells.next(), Some(vec![0, 0]));
+    assert_eq!(grid.cells.count(), 3);
+}
+
+#[test]
+fn test_iproduct() {
+    let v1 = [1, 2];
+    let v2 = ["a", "b"];
+    let v3 = ['x', 'y'];
+    let v4 = [0];
+    let mut expected = Vec::new();
+    for a in v1 {
+        for b in v2 {
+            for c in v3 {
+                for d in v4 {
+                    expected.push((a, b, c, d));
+                }
+            }
+        }
+    }
+    let actual = iproduct!(v1, v2, v3, v4).collect::<Vec<_>>();
+
+    assert_eq!(expected, actual);
+    assert_eq!(iproduct!(v1, v2, v3, v4).count(), expected.len());
+    assert_eq!(iproduct!(v1, v2, v3, v4).size_hint(), (expected.len(), Some(expected.len())));
+}
+
+#[test]
+fn test_iproduct_small() {
+    assert_eq!(iproduct!().collect::<Vec<_>>(), [()]);
+    assert_eq!(iproduct!([1, 2]).collect::<Vec<_>>(), [(1,), (2,)]);
+    assert_eq!(iproduct!(0..2, "ab".chars(),).collect::<Vec<_>>(), [(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b')]);
+}
+
+#[test]
+fn test_iproduct17() {
+    let bits = [false, true];
+    let iter = iproduct!(bits, bits, bits, bits, bits, bits, bits, bits, bits, bits, bits, bits, bits, bits, bits, bits, bits);
+
+    assert_eq!(iter.count(), 1 << 17);
+}
+
+#[test]
+fn test_product_repeat() {
+    let expected = vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1]];
+    let actual: Vec<Vec<u8>> = (0..2).product_repeat(2).collect();
+
+    assert_eq!(expected, actual);
+    assert_eq!(product_repeat(0..2, 0).collect::<Vec<Vec<u8>>>(), [Vec::<u8>::new()]);
+    assert_eq!(product_repeat(0..0, 3).count(), 0);
+}
+
+#[test]
+fn test_product_repeat_pins() {
+    let digits = "0123456789";
+    let mut iter = product_repeat(digits.chars(), 4);
+
+    assert_eq!(iter.next().map(String::from_iter).as_deref(), Some("0000"));
+    assert_eq!(iter.nth(1233).map(String::from_iter).as_deref(), Some("1234"));
+    assert_eq!(iter.count(), 10_000 - 1235);
+
+    let mut iter = (0..10).product_repeat(4);
+    let mut count = 0;
+    while let Some(pin) = iter.next_slice() {
+        assert_eq!(pin.len(), 4);
+        count += 1;
+    }
+    assert_eq!(count, 10_000);
+}
+
+#[test]
+fn test_product_repeat_size_hint() {
+    let len = 3 * 3 * 3;
+
+    assert_exact_remaining(product_repeat(0..3, 3), len);
+
+    assert_eq!(product_repeat(0..2u32, 63).size_hint(), (1 << 63, Some(1 << 63)));
+    assert_eq!(product_repeat(0..2u32, 64).size_hint(), (usize::MAX, None));
+}
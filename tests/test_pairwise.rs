@@ -1,8 +1,16 @@
-use itertools::{Pairwiseable, pairwise};
+use std::collections::VecDeque;
+
+use itertools::{
+    Pairwiseable, SlicePairwiseable, circular_pairwise, circular_pairwise_slice, pairwise,
+    pairwise_slice,
+};
+
+mod common;
+use common::assert_exact_remaining;
 
 #[test]
 fn test_pairwise0() {
-    let expected = [(0u32, 032); 0];
+    let expected = [(0u32, 0u32); 0];
     let actual: Vec<(u32, u32)> = pairwise([].into_iter()).collect();
 
     assert_eq!(&expected[..], &actual[..]);
@@ -42,3 +50,183 @@ fn test_pairwise5() {
     assert_eq!(pairwise(values.chars()).count(), expected.len());
     assert_eq!(pairwise(values.chars()).size_hint(), (1, Some(expected.len())));
 }
+
+#[test]
+fn test_circular_pairwise0() {
+    let expected = [(0u32, 0u32); 0];
+    let actual: Vec<(u32, u32)> = circular_pairwise([].into_iter()).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(circular_pairwise([0; 0].into_iter()).count(), expected.len());
+    assert_eq!(circular_pairwise([0; 0].into_iter()).size_hint(), (expected.len(), Some(expected.len())));
+}
+
+#[test]
+fn test_circular_pairwise1() {
+    let values = "a";
+    let expected = [('a', 'a')];
+    let actual: Vec<_> = values.chars().circular_pairwise().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.chars().circular_pairwise().count(), expected.len());
+}
+
+#[test]
+fn test_circular_pairwise4() {
+    let values = [1, 2, 3, 4];
+    let expected = [(1, 2), (2, 3), (3, 4), (4, 1)];
+    let actual: Vec<_> = values.into_iter().circular_pairwise().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    assert_exact_remaining(values.into_iter().circular_pairwise(), expected.len());
+}
+
+#[test]
+fn test_pairwise_slice() {
+    // neither Clone nor Copy
+    #[derive(Debug, PartialEq)]
+    struct Vertex(i32, i32);
+
+    let values = [Vertex(0, 0), Vertex(1, 0), Vertex(1, 1)];
+    let expected = [(&values[0], &values[1]), (&values[1], &values[2])];
+    let actual: Vec<_> = values.pairwise().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.pairwise().len(), expected.len());
+
+    let expected = [(&values[0], &values[1]), (&values[1], &values[2]), (&values[2], &values[0])];
+    let actual: Vec<_> = values.circular_pairwise().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.circular_pairwise().len(), expected.len());
+
+    let actual: Vec<_> = circular_pairwise_slice(&values).rev().collect();
+    let mut reversed = expected.to_vec();
+    reversed.reverse();
+
+    assert_eq!(reversed, actual);
+}
+
+#[test]
+fn test_pairwise_slice_short() {
+    let values: [u32; 0] = [];
+
+    assert_eq!(pairwise_slice(&values).count(), 0);
+    assert_eq!(circular_pairwise_slice(&values).count(), 0);
+
+    let values = ["a"];
+
+    assert_eq!(pairwise_slice(&values).next(), None);
+    assert_eq!(circular_pairwise_slice(&values).collect::<Vec<_>>(), [(&"a", &"a")]);
+}
+
+#[test]
+fn test_pairwise_exact_size() {
+    let values = [1, 2, 3, 4, 5];
+    let expected = [(1, 2), (2, 3), (3, 4), (4, 5)];
+
+    assert_exact_remaining(pairwise(values.into_iter()), expected.len());
+
+    assert_eq!(pairwise([0u32; 0].into_iter()).len(), 0);
+    assert_eq!(pairwise([0u32].into_iter()).len(), 0);
+}
+
+#[test]
+fn test_pairwise_rev0() {
+    let expected = [(0u32, 0u32); 0];
+    let actual: Vec<(u32, u32)> = pairwise([].into_iter()).rev().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_pairwise_rev1() {
+    let values = ['a'];
+    let mut iter = values.into_iter().pairwise();
+
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn test_pairwise_rev() {
+    let values = ['a', 'b', 'c', 'd', 'e'];
+    let expected = [('d', 'e'), ('c', 'd'), ('b', 'c'), ('a', 'b')];
+    let actual: Vec<_> = values.into_iter().pairwise().rev().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    assert_exact_remaining(values.into_iter().pairwise().rev(), expected.len());
+}
+
+// Runs dry once after dry_after calls, then goes on yielding its items.
+struct Unfused {
+    items: VecDeque<u32>,
+    dry_after: usize,
+}
+
+impl Unfused {
+    fn dry(&mut self) -> bool {
+        let dry = self.dry_after == 0;
+        self.dry_after = self.dry_after.wrapping_sub(1);
+        dry
+    }
+}
+
+impl Iterator for Unfused {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.dry() { None } else { self.items.pop_front() }
+    }
+}
+
+impl DoubleEndedIterator for Unfused {
+    fn next_back(&mut self) -> Option<u32> {
+        if self.dry() { None } else { self.items.pop_back() }
+    }
+}
+
+#[test]
+fn test_pairwise_fused() {
+    let mut iter = pairwise(Unfused { items: VecDeque::from([1, 2, 3]), dry_after: 1 });
+
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = pairwise(Unfused { items: VecDeque::from([1, 2, 3]), dry_after: 3 });
+
+    assert_eq!(iter.next_back(), Some((2, 3)));
+    assert_eq!(iter.next_back(), Some((1, 2)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_pairwise_both_ends() {
+    let values = ['a', 'b', 'c', 'd', 'e'];
+    let mut iter = values.into_iter().pairwise();
+
+    assert_eq!(iter.next_back(), Some(('d', 'e')));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(('a', 'b')));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(('c', 'd')));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(('b', 'c')));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = values.into_iter().pairwise();
+
+    assert_eq!(iter.next_back(), Some(('d', 'e')));
+    assert_eq!(iter.next(), Some(('a', 'b')));
+    assert_eq!(iter.next(), Some(('b', 'c')));
+    assert_eq!(iter.next(), Some(('c', 'd')));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
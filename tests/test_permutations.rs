@@ -1,4 +1,12 @@
-use itertools::{permutations, Permutable};
+use itertools::{
+    index_permutations, into_permutations, lexicographic_permutation_rank,
+    lexicographic_permutation_unrank, lexicographic_permutations, next_permutation,
+    partial_permutations, permutation_rank, permutation_unrank, permutations, prev_permutation,
+    IndexPermutations, IntoPermutable, LexicographicPermutations, Permutable, Permutations,
+};
+
+mod common;
+use common::assert_exact_remaining;
 
 #[test]
 fn test_permutations0() {
@@ -63,3 +71,334 @@ fn test_permutations5() {
         assert_eq!(iter.size_hint(), (upper, Some(upper)));
     }
 }
+
+#[test]
+fn test_partial_permutations0() {
+    let values = ['a', 'b', 'c'];
+    let expected: Vec<Vec<&char>> = vec![Vec::new()];
+    let actual: Vec<Vec<&char>> = values.partial_permutations(0).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.partial_permutations(0).count(), expected.len());
+    assert_eq!(values.partial_permutations(0).size_hint(), (expected.len(), Some(expected.len())));
+}
+
+#[test]
+fn test_partial_permutations_too_long() {
+    let values = ['a', 'b', 'c'];
+    let expected: Vec<Vec<&char>> = Vec::new();
+    let actual: Vec<Vec<&char>> = values.partial_permutations(4).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.partial_permutations(4).count(), expected.len());
+    assert_eq!(values.partial_permutations(4).size_hint(), (expected.len(), Some(expected.len())));
+}
+
+#[test]
+fn test_partial_permutations2() {
+    let values = "abcd".chars().collect::<Vec<_>>();
+    let expected = [
+        "ab", "ac", "ad", "ba", "bc", "bd", "ca", "cb", "cd", "da", "db", "dc",
+    ];
+    let actual = partial_permutations(&values, 2).map(
+        |item| item.iter().cloned().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    assert_exact_remaining(partial_permutations(&values, 2), expected.len());
+}
+
+#[test]
+fn test_partial_permutations_full() {
+    let values = "abcd".chars().collect::<Vec<_>>();
+    let mut expected = permutations(&values).collect::<Vec<_>>();
+    expected.sort();
+    let actual = partial_permutations(&values, values.len()).collect::<Vec<_>>();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_partial_permutations_count() {
+    let workers: Vec<u32> = (0..12).collect();
+
+    assert_eq!(workers.partial_permutations(3).count(), 12 * 11 * 10);
+    assert_eq!(workers.partial_permutations(3).size_hint(), (1320, Some(1320)));
+    assert_eq!(workers.partial_permutations(3).collect::<Vec<_>>().len(), 1320);
+}
+
+#[test]
+fn test_lexicographic_permutations() {
+    let values = ['c', 'a', 'b'];
+    let expected = ["abc", "acb", "bac", "bca", "cab", "cba"];
+    let actual = lexicographic_permutations(&values).map(
+        |item| item.iter().cloned().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_lexicographic_permutations_duplicates() {
+    let values = ['a', 'b', 'a', 'b'];
+    let expected = ["aabb", "abab", "abba", "baab", "baba", "bbaa"];
+    let actual = values.lexicographic_permutations().map(
+        |item| item.iter().cloned().collect::<String>()
+    ).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_lexicographic_permutations0() {
+    let values = [0u32; 0];
+    let expected: Vec<Vec<&u32>> = vec![Vec::new()];
+    let actual: Vec<Vec<&u32>> = values.lexicographic_permutations().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_lexicographic_permutations_size_hint() {
+    let values = ['a', 'b', 'c'];
+    let mut iter = values.lexicographic_permutations();
+
+    for n in 0..6 {
+        assert_eq!(iter.size_hint(), (1, Some(6 - n)));
+        assert!(iter.next().is_some());
+    }
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+
+    let values = ['a', 'a', 'a'];
+    let mut iter = values.lexicographic_permutations();
+
+    assert_eq!(iter.size_hint(), (1, Some(6)));
+    assert!(iter.next().is_some());
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_next_prev_permutation() {
+    let mut values = [1, 2, 2, 3];
+    let mut forward = vec![values];
+
+    while next_permutation(&mut values) {
+        forward.push(values);
+    }
+
+    assert_eq!(forward.len(), 12);
+    assert!(forward.windows(2).all(|pair| pair[0] < pair[1]));
+    // wrapped around to the first permutation
+    assert_eq!(values, [1, 2, 2, 3]);
+
+    assert!(!prev_permutation(&mut values));
+    assert_eq!(values, [3, 2, 2, 1]);
+
+    let mut backward = vec![values];
+
+    while prev_permutation(&mut values) {
+        backward.push(values);
+    }
+
+    backward.reverse();
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn test_index_permutations() {
+    let values = ["x", "y", "z"];
+    let expected: Vec<Vec<&&str>> = permutations(&values).collect();
+    let actual: Vec<Vec<&&str>> = index_permutations(values.len()).map(
+        |indices| indices.into_iter().map(|index| &values[index]).collect()
+    ).collect();
+
+    assert_eq!(expected, actual);
+    assert_eq!(index_permutations(0).collect::<Vec<_>>(), vec![Vec::<usize>::new()]);
+    assert_eq!(index_permutations(4).count(), 24);
+
+    let mut iter = index_permutations(4);
+    iter.nth(4);
+    assert_eq!(iter.size_hint(), (19, Some(19)));
+}
+
+#[test]
+fn test_into_permutations() {
+    let values = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let expected: Vec<Vec<String>> = permutations(&values).map(
+        |item| item.into_iter().cloned().collect()
+    ).collect();
+    let actual: Vec<Vec<String>> = values.into_permutations().collect();
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected.len(), 6);
+}
+
+#[test]
+fn test_into_permutations_iter() {
+    let values = "abcd";
+    let mut expected = [
+        "abcd", "abdc", "acbd", "acdb", "adbc", "adcb", "bacd", "badc",
+        "bcad", "bcda", "bdac", "bdca", "cabd", "cadb", "cbad", "cbda",
+        "cdab", "cdba", "dabc", "dacb", "dbac", "dbca", "dcab", "dcba",
+    ].map(String::from);
+    expected.sort();
+
+    let mut actual: Vec<String> = values.chars().map(String::from).into_permutations().map(
+        |item| item.concat()
+    ).collect();
+    actual.sort();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    assert_exact_remaining(into_permutations(values.chars()), expected.len());
+}
+
+#[test]
+fn test_permutation_rank() {
+    for n in 0..=6 {
+        let expected: Vec<Vec<usize>> = index_permutations(n).collect();
+
+        for (rank, permutation) in expected.iter().enumerate() {
+            assert_eq!(permutation_unrank(n, rank).as_ref(), Some(permutation));
+            assert_eq!(permutation_rank(permutation), Some(rank));
+        }
+
+        assert_eq!(permutation_unrank(n, expected.len()), None);
+    }
+
+    let values = ['a', 'b', 'c', 'd', 'e'];
+    for (rank, permutation) in permutations(&values).enumerate() {
+        let indices: Vec<usize> = permutation.iter().map(|&&item| item as usize - 'a' as usize).collect();
+        assert_eq!(permutation_rank(&indices), Some(rank));
+    }
+
+    assert_eq!(permutation_rank(&[0, 0]), None);
+    assert_eq!(permutation_rank(&[0, 2]), None);
+
+    let expected = index_permutations(12).nth(479001599);
+    assert_eq!(permutation_unrank(12, 479001599), expected);
+    assert_eq!(permutation_rank(&expected.unwrap()), Some(479001599));
+
+    for rank in [0, 1, 12345, usize::MAX / 3, usize::MAX] {
+        let permutation = permutation_unrank(30, rank).unwrap();
+        assert_eq!(IndexPermutations::from_rank(30, rank).next().as_ref(), Some(&permutation));
+        assert_eq!(permutation_rank(&permutation), Some(rank));
+    }
+
+    // past the ranks that fit into usize it keeps on stepping
+    let mut iter = IndexPermutations::from_rank(30, usize::MAX - 1);
+    assert_eq!(iter.nth(1), permutation_unrank(30, usize::MAX));
+    let mut expected = iter.clone();
+    expected.next();
+    assert_eq!(iter.nth(1), expected.next());
+}
+
+#[test]
+fn test_lexicographic_permutation_rank() {
+    for n in 0..=5 {
+        let values: Vec<usize> = (0..n).collect();
+        let expected: Vec<Vec<usize>> = values.lexicographic_permutations().map(
+            |item| item.into_iter().copied().collect()
+        ).collect();
+
+        for (rank, permutation) in expected.iter().enumerate() {
+            assert_eq!(lexicographic_permutation_unrank(n, rank).as_ref(), Some(permutation));
+            assert_eq!(lexicographic_permutation_rank(permutation), Some(rank));
+        }
+
+        assert_eq!(lexicographic_permutation_unrank(n, expected.len()), None);
+    }
+
+    assert_eq!(lexicographic_permutation_rank(&[0, 0]), None);
+    assert_eq!(lexicographic_permutation_rank(&[0, 2]), None);
+    assert_eq!(lexicographic_permutation_unrank(12, 479001599), Some((0..12).rev().collect()));
+}
+
+#[test]
+fn test_lexicographic_permutation_unrank_then_iterate() {
+    let expected: Vec<Vec<usize>> = (0..24).map(
+        |rank| lexicographic_permutation_unrank(4, rank).unwrap()
+    ).collect();
+    let mut indices = lexicographic_permutation_unrank(4, 10).unwrap();
+    let mut actual = vec![indices.clone()];
+
+    while next_permutation(&mut indices) {
+        actual.push(indices.clone());
+    }
+
+    assert_eq!(&expected[10..], &actual[..]);
+}
+
+#[test]
+fn test_index_permutations_from_rank() {
+    for n in 0..=6 {
+        let expected: Vec<Vec<usize>> = index_permutations(n).collect();
+
+        for rank in 0..=expected.len() + 1 {
+            let actual: Vec<Vec<usize>> = IndexPermutations::from_rank(n, rank).collect();
+            assert_eq!(&expected[rank.min(expected.len())..], &actual[..]);
+            assert_eq!(index_permutations(n).nth(rank).as_ref(), expected.get(rank));
+
+            let iter = IndexPermutations::from_rank(n, rank);
+            let len = expected.len().saturating_sub(rank);
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+        }
+
+        // nth() continues from where the iterator is
+        let mut iter = index_permutations(n);
+        let mut rank = 0;
+        for step in 0..4 {
+            assert_eq!(iter.nth(step).as_ref(), expected.get(rank + step));
+            rank += step + 1;
+        }
+        assert_eq!(iter.next().as_ref(), expected.get(rank));
+    }
+
+    let expected: Vec<Vec<usize>> = index_permutations(8).collect();
+    for rank in (0..expected.len()).step_by(997) {
+        assert_eq!(IndexPermutations::from_rank(8, rank).next().as_ref(), expected.get(rank));
+    }
+}
+
+#[test]
+fn test_permutations_from_rank() {
+    let values = ['a', 'b', 'c', 'd', 'e'];
+    let expected: Vec<Vec<&char>> = permutations(&values).collect();
+
+    assert_eq!(permutations(&[0, 1, 2]).nth(3), Some(vec![&0, &2, &1]));
+    assert_eq!(values.permutations().nth(100).as_ref(), expected.get(100));
+    assert_eq!(values.permutations().nth(120), None);
+
+    // split the work into chunks that are each started by rank
+    let mut actual = Vec::new();
+    for start in (0..expected.len()).step_by(25) {
+        actual.extend(Permutations::from_rank(&values, start).take(25));
+    }
+
+    assert_eq!(expected, actual);
+
+    let expected: Vec<Vec<char>> = into_permutations(values).collect();
+    assert_eq!(into_permutations(values).nth(42).as_ref(), expected.get(42));
+}
+
+#[test]
+fn test_lexicographic_permutations_from_rank() {
+    let values = ['b', 'a', 'c', 'a'];
+    let expected: Vec<Vec<&char>> = values.lexicographic_permutations().collect();
+
+    for rank in 0..=expected.len() {
+        let actual: Vec<Vec<&char>> = LexicographicPermutations::from_rank(&values, rank).collect();
+        assert_eq!(&expected[rank..], &actual[..]);
+    }
+
+    let values = [3, 1, 2, 0];
+    for rank in 0..24 {
+        let actual: Vec<usize> = LexicographicPermutations::from_rank(&values, rank).next().unwrap()
+            .into_iter().copied().collect();
+        assert_eq!(lexicographic_permutation_unrank(4, rank), Some(actual));
+    }
+    assert_eq!(LexicographicPermutations::from_rank(&values, 24).next(), None);
+}
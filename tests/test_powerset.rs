@@ -1,4 +1,9 @@
-use itertools::{Powersetable, powerset};
+use std::ops::{Bound, Range};
+
+use itertools::{Powerset, PowersetRange, Powersetable, powerset, powerset_range};
+
+mod common;
+use common::assert_exact_remaining;
 
 #[test]
 fn test_powerset0() {
@@ -32,3 +37,133 @@ fn test_powerset3() {
 
     assert_eq!(&expected[..], &actual[..]);
 }
+
+#[test]
+fn test_powerset_size_hint() {
+    for len in 0..=6 {
+        let values: Vec<u32> = (0..len).collect();
+        let total = 1usize << len;
+
+        assert_eq!(powerset(values.iter()).count(), total);
+        assert_eq!(powerset(values.iter()).size_hint(), (total, Some(total)));
+
+        assert_exact_remaining(powerset(values.iter()), total);
+    }
+}
+
+#[test]
+fn test_powerset_size_hint_overflow() {
+    let values: Vec<u32> = (0..64).collect();
+
+    assert_eq!(powerset(values.iter()).size_hint(), (usize::MAX, None));
+
+    let mut iter: Powerset<Range<u32>> = powerset(0..70);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    assert_eq!(iter.next_back().map(|subset| subset.len()), Some(70));
+    assert_eq!(iter.next(), Some(vec![]));
+
+    let mut iter: PowersetRange<Range<u32>> = powerset_range(0..70, 68..);
+    let expected = 2415 + 70 + 1;
+    assert_eq!(iter.size_hint(), (expected, Some(expected)));
+    assert_eq!(iter.next_back().map(|subset| subset.len()), Some(70));
+}
+
+#[test]
+fn test_powerset_range() {
+    let values = "abcd";
+    let expected = ["ab", "ac", "ad", "bc", "bd", "cd", "abc", "abd", "acd", "bcd"].map(str::to_string);
+    let actual: Vec<String> = powerset_range(values.chars(), 2..=3).map(
+        |item| item.into_iter().collect()
+    ).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    let actual: Vec<String> = values.chars().powerset_range(3..).map(
+        |item| item.into_iter().collect()
+    ).collect();
+    assert_eq!(actual, ["abc", "abd", "acd", "bcd", "abcd"]);
+
+    let actual: Vec<String> = values.chars().powerset_range(..2).map(
+        |item| item.into_iter().collect()
+    ).collect();
+    assert_eq!(actual, ["", "a", "b", "c", "d"]);
+
+    assert_eq!(values.chars().powerset_range(..).count(), 16);
+    assert_eq!(values.chars().powerset_range(5..).count(), 0);
+    assert_eq!(values.chars().powerset_range(2..2).count(), 0);
+    assert_eq!(values.chars().powerset_range((Bound::Excluded(2), Bound::Excluded(3))).count(), 0);
+}
+
+#[test]
+fn test_powerset_range_size_hint() {
+    let values: Vec<u32> = (0..6).collect();
+
+    for start in 0..=7 {
+        for end in start..=7 {
+            let total: usize = powerset(values.iter()).filter(
+                |item| (start..end).contains(&item.len())
+            ).count();
+            assert_eq!(powerset_range(values.iter(), start..end).count(), total);
+
+            assert_exact_remaining(powerset_range(values.iter(), start..end), total);
+        }
+    }
+
+    let values: Vec<u32> = (0..30).collect();
+    let expected = 435 + 4060 + 27405;
+    assert_eq!(powerset_range(values.iter(), 2..=4).size_hint(), (expected, Some(expected)));
+}
+
+#[test]
+fn test_powerset_rev() {
+    let values = "abc";
+    let expected = ["abc", "bc", "ac", "ab", "c", "b", "a", ""];
+    let actual: Vec<String> = values.chars().powerset().rev().map(
+        |item| item.into_iter().collect()
+    ).collect();
+
+    assert_eq!(actual, expected);
+
+    let actual: Vec<String> = values.chars().powerset_range(1..=2).rev().map(
+        |item| item.into_iter().collect()
+    ).collect();
+
+    assert_eq!(actual, ["bc", "ac", "ab", "c", "b", "a"]);
+}
+
+#[test]
+fn test_powerset_double_ended() {
+    let values: Vec<u32> = (0..5).collect();
+    let expected: Vec<_> = powerset(values.iter()).collect();
+
+    for front in 0..=expected.len() {
+        let mut iter = powerset(values.iter());
+        let mut actual: Vec<_> = iter.by_ref().take(front).collect();
+        let mut back: Vec<_> = Vec::new();
+
+        let len = expected.len() - front;
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+
+        while let Some(item) = iter.next_back() {
+            back.push(item);
+            let len = expected.len() - actual.len() - back.len();
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+        }
+        assert_eq!(iter.next(), None);
+
+        back.reverse();
+        actual.extend(back);
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn test_powerset_single_pass() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    let values = (0..4).inspect(|_| calls.set(calls.get() + 1));
+
+    assert_eq!(values.powerset().count(), 16);
+    assert_eq!(calls.get(), 4);
+}
@@ -1,9 +1,12 @@
-use itertools::{product, product4, Productable};
+use itertools::{iproduct, product, product_rank, product_unrank, Productable};
+
+mod common;
+use common::assert_exact_remaining;
 
 #[test]
 fn test_product() {
     let first = ["foo", "bar", "baz"];
-    let second = vec![1, 2, 3, 4];
+    let second = [1, 2, 3, 4];
     let expected = vec![
         ("foo", 1),
         ("foo", 2),
@@ -45,7 +48,7 @@ fn test_product() {
 #[test]
 fn test_product_first_empty() {
     let first: [&str; 0] = [];
-    let second = vec![1, 2, 3, 4];
+    let second = [1, 2, 3, 4];
     let expected = vec![];
 
     assert_eq!(
@@ -100,7 +103,10 @@ fn test_product_both_empty() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_product4() {
+    use itertools::product4;
+
     let v1 = [1, 2];
     let v2 = ["a", "b"];
     let v3 = ['x', 'y'];
@@ -128,3 +134,124 @@ fn test_product4() {
     iter.next();
     assert_eq!(iter.count(), expected.len() - 1);
 }
+
+#[test]
+fn test_product_rev() {
+    let first = ["foo", "bar", "baz"];
+    let second = [1, 2, 3, 4];
+    let mut expected = product(first.into_iter(), second.into_iter()).collect::<Vec<_>>();
+    expected.reverse();
+
+    assert_eq!(
+        product(first.into_iter(), second.into_iter()).rev().collect::<Vec<_>>(),
+        expected
+    );
+
+    assert_eq!(product(first.into_iter(), second.into_iter()).len(), expected.len());
+    assert_exact_remaining(product(first.into_iter(), second.into_iter()).rev(), expected.len());
+}
+
+#[test]
+fn test_product_both_ends() {
+    let first = [1, 2, 3];
+    let second = ['a', 'b'];
+    let expected = product(first.into_iter(), second.into_iter()).collect::<Vec<_>>();
+
+    // every way of taking items alternately from both ends
+    for pattern in 0..(1u32 << expected.len()) {
+        let mut iter = product(first.into_iter(), second.into_iter());
+        let mut front = 0;
+        let mut back = expected.len();
+
+        for step in 0..expected.len() {
+            assert_eq!(iter.len(), back - front);
+            if pattern & (1 << step) == 0 {
+                assert_eq!(iter.next(), Some(expected[front]));
+                front += 1;
+            } else {
+                back -= 1;
+                assert_eq!(iter.next_back(), Some(expected[back]));
+            }
+        }
+
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+#[test]
+fn test_product_nth() {
+    let first = [1, 2, 3, 4];
+    let second = ['a', 'b', 'c'];
+    let expected = product(first.into_iter(), second.into_iter()).collect::<Vec<_>>();
+
+    for start in 0..=expected.len() {
+        for n in 0..=expected.len() + 1 {
+            let mut iter = product(first.into_iter(), second.into_iter());
+            if start > 0 {
+                iter.nth(start - 1);
+            }
+            assert_eq!(iter.nth(n), expected.get(start + n).copied());
+            assert_eq!(iter.len(), expected.len().saturating_sub(start + n + 1));
+            assert_eq!(iter.collect::<Vec<_>>(), expected[(start + n + 1).min(expected.len())..]);
+
+            let mut iter = product(first.into_iter(), second.into_iter());
+            if start > 0 {
+                iter.nth_back(start - 1);
+            }
+            let back = expected.len() - start;
+            let value = if n < back { Some(expected[back - n - 1]) } else { None };
+            assert_eq!(iter.nth_back(n), value);
+            assert_eq!(iter.rev().collect::<Vec<_>>().len(), back.saturating_sub(n + 1));
+        }
+    }
+
+    let mut iter = product(first.into_iter(), second.into_iter());
+    assert_eq!(iter.nth_back(1), Some((4, 'b')));
+    assert_eq!(iter.nth(9), Some((4, 'a')));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_product_nth_not_exact() {
+    let first = "abc";
+    let second = "xy";
+    let expected = product(first.chars(), second.chars()).collect::<Vec<_>>();
+
+    for n in 0..=expected.len() {
+        let mut iter = product(first.chars(), second.chars());
+        assert_eq!(iter.nth(n), expected.get(n).copied());
+    }
+}
+
+#[test]
+fn test_product_nth_large() {
+    let mut iter = product(0..1_000_000u32, 0..1_000_000u32);
+
+    assert_eq!(iter.nth(123_456_789_012), Some((123_456, 789_012)));
+    assert_eq!(iter.nth_back(0), Some((999_999, 999_999)));
+    assert_eq!(iter.len(), 1_000_000 * 1_000_000 - 123_456_789_012 - 2);
+}
+
+#[test]
+fn test_product_rank() {
+    let v1 = [1, 2];
+    let v2 = ["a", "b", "c"];
+    let v3 = ['x', 'y'];
+    let lengths = [v1.len(), v2.len(), v3.len()];
+    let expected = iproduct!(v1, v2, v3).collect::<Vec<_>>();
+
+    for (rank, item) in expected.iter().enumerate() {
+        let indices = product_unrank(&lengths, rank).unwrap();
+        assert_eq!((v1[indices[0]], v2[indices[1]], v3[indices[2]]), *item);
+        assert_eq!(product_rank(&lengths, &indices), Some(rank));
+    }
+
+    assert_eq!(product_unrank(&lengths, expected.len()), None);
+    assert_eq!(product_unrank(&[2, 0], 0), None);
+    assert_eq!(product_unrank(&[], 0), Some(vec![]));
+    assert_eq!(product_rank(&lengths, &[0, 3, 0]), None);
+    assert_eq!(product_rank(&lengths, &[0, 0]), None);
+}
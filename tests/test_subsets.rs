@@ -0,0 +1,82 @@
+use itertools::{
+    GrayCodePowersetable, SubsetDelta, gray_code_powerset, subset_masks_u128, subset_masks_u64,
+};
+
+mod common;
+use common::assert_exact_remaining;
+
+#[test]
+fn test_gray_code_powerset0() {
+    let values = "";
+    let expected: Vec<SubsetDelta<char>> = Vec::new();
+    let actual: Vec<_> = values.chars().gray_code_powerset().collect();
+
+    assert_eq!(expected, actual);
+    assert_eq!(values.chars().gray_code_powerset().size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_gray_code_powerset3() {
+    use SubsetDelta::{Added, Removed};
+
+    let values = "abc";
+    let expected = [
+        Added('a'), Added('b'), Removed('a'), Added('c'), Added('a'), Removed('b'), Removed('a'),
+    ];
+    let actual: Vec<_> = gray_code_powerset(values.chars()).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    assert_exact_remaining(gray_code_powerset(values.chars()), expected.len());
+}
+
+#[test]
+fn test_gray_code_powerset_subsets() {
+    let values = [1, 2, 3, 4, 5];
+    let mut iter = gray_code_powerset(values);
+    let mut subsets = vec![iter.mask()];
+    let mut current: Vec<i32> = Vec::new();
+
+    while let Some(delta) = iter.next() {
+        match delta {
+            SubsetDelta::Added(value) => current.push(value),
+            SubsetDelta::Removed(value) => current.retain(|&item| item != value),
+        }
+        current.sort();
+
+        let subset: Vec<i32> = iter.subset().into_iter().copied().collect();
+        assert_eq!(current, subset);
+        assert_eq!((subsets.last().unwrap() ^ iter.mask()).count_ones(), 1);
+        subsets.push(iter.mask());
+    }
+
+    // every subset exactly once
+    subsets.sort();
+    assert_eq!(subsets, (0..32).collect::<Vec<u128>>());
+    assert_eq!(iter.mask(), 1 << 4);
+}
+
+#[test]
+fn test_subset_masks() {
+    assert_eq!(subset_masks_u64(0).collect::<Vec<_>>(), [0]);
+    assert_eq!(subset_masks_u64(2).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert_eq!(subset_masks_u64(2).rev().collect::<Vec<_>>(), [3, 2, 1, 0]);
+    assert_eq!(subset_masks_u64(10).size_hint(), (1024, Some(1024)));
+    assert_eq!(subset_masks_u64(10).count(), 1024);
+    assert_eq!(subset_masks_u128(100).size_hint(), (usize::MAX, None));
+
+    let mut iter = subset_masks_u64(64);
+    assert_eq!(iter.next_back(), Some(u64::MAX));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.size_hint(), (usize::MAX - 1, Some(usize::MAX - 1)));
+
+    let mut iter = subset_masks_u128(128);
+    assert_eq!(iter.next_back(), Some(u128::MAX));
+    assert_eq!(iter.next_back(), Some(u128::MAX - 1));
+
+    let values = ['a', 'b', 'c'];
+    let subsets: Vec<String> = subset_masks_u64(values.len()).map(
+        |mask| values.iter().enumerate().filter(|(index, _)| mask & (1 << index) != 0).map(|(_, value)| value).collect()
+    ).collect();
+    assert_eq!(subsets, ["", "a", "b", "ab", "c", "ac", "bc", "abc"]);
+}
@@ -0,0 +1,85 @@
+use itertools::{Windowable, array_windows, tuple_windows};
+
+mod common;
+use common::assert_exact_remaining;
+
+#[test]
+fn test_array_windows0() {
+    let values = [0u32; 0];
+    let expected = [[0u32; 3]; 0];
+    let actual: Vec<[u32; 3]> = array_windows(values.into_iter()).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(array_windows::<_, 3>(values.into_iter()).count(), expected.len());
+    assert_eq!(array_windows::<_, 3>(values.into_iter()).size_hint(), (expected.len(), Some(expected.len())));
+}
+
+#[test]
+fn test_array_windows_short() {
+    let values = "ab";
+    let expected = [['\0'; 3]; 0];
+    let actual: Vec<[char; 3]> = values.chars().array_windows().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.chars().array_windows::<3>().count(), expected.len());
+}
+
+#[test]
+fn test_array_windows3() {
+    let values = "abcde";
+    let expected = [['a', 'b', 'c'], ['b', 'c', 'd'], ['c', 'd', 'e']];
+    let actual: Vec<[char; 3]> = values.chars().array_windows().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.chars().array_windows::<3>().count(), expected.len());
+}
+
+#[test]
+fn test_array_windows_size_hint() {
+    let values = [1, 2, 3, 4, 5, 6, 7];
+    let expected: Vec<[i32; 5]> = values.windows(5).map(|window| window.try_into().unwrap()).collect();
+    let actual: Vec<[i32; 5]> = values.into_iter().array_windows().collect();
+
+    assert_eq!(expected, actual);
+
+    assert_exact_remaining(values.into_iter().array_windows::<5>(), expected.len());
+
+    assert_eq!(values.into_iter().array_windows::<5>().len(), 3);
+    assert_eq!(values.into_iter().array_windows::<1>().len(), 7);
+}
+
+#[test]
+fn test_array_windows_moving_average() {
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let expected = [3.0, 4.0];
+    let actual: Vec<f64> = values.into_iter().array_windows::<5>().map(
+        |window| window.iter().sum::<f64>() / 5.0
+    ).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_tuple_windows3() {
+    let values = "the quick brown fox";
+    let expected = [("the", "quick", "brown"), ("quick", "brown", "fox")];
+    let actual: Vec<(&str, &str, &str)> = values.split(' ').tuple_windows().collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(values.split(' ').tuple_windows::<(_, _, _)>().count(), expected.len());
+}
+
+#[test]
+fn test_tuple_windows_size_hint() {
+    let values = [1, 2, 3, 4];
+    let expected = [(1, 2), (2, 3), (3, 4)];
+    let actual: Vec<(i32, i32)> = tuple_windows(values.into_iter()).collect();
+
+    assert_eq!(&expected[..], &actual[..]);
+
+    assert_exact_remaining(tuple_windows::<_, (i32, i32)>(values.into_iter()), expected.len());
+
+    assert_eq!(values.into_iter().tuple_windows::<(_, _, _, _)>().len(), 1);
+    assert_eq!(values.into_iter().tuple_windows::<(_, _, _, _, _)>().len(), 0);
+    assert_eq!(values.into_iter().tuple_windows::<(_, _, _, _, _)>().next(), None);
+}
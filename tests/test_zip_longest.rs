@@ -1,4 +1,6 @@
-use itertools::ZippableLongest;
+use itertools::{
+    EitherOrBoth, ZippableLongest, zip_longest4, zip_longest_either, zip_longest_fill, zip_longest_with,
+};
 
 #[test]
 fn test_zip_longest_both_empty() {
@@ -47,3 +49,204 @@ fn test_zip_longest() {
     assert_eq!(first.chars().zip_longest(second.chars()).count(), expected.len());
     assert_eq!(first.chars().zip_longest(second.chars()).size_hint(), (1, Some(expected.len())));
 }
+
+#[test]
+fn test_zip_longest3() {
+    let first = "abc";
+    let second = [1, 2];
+    let third = ["x", "y", "z", "w"];
+    let expected = [('a', 1, "x"), ('b', 2, "y"), ('c', 0, "z"), ('\0', 0, "w")];
+    let actual = first.chars().zip_longest3(second.into_iter(), third.into_iter()).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(first.chars().zip_longest3(second.into_iter(), third.into_iter()).count(), expected.len());
+}
+
+#[test]
+fn test_zip_longest4() {
+    let v1 = [1, 2];
+    let v2 = ["a"];
+    let v3: [char; 0] = [];
+    let v4 = [1.5, 2.5, 3.5];
+    let expected = [(1, "a", '\0', 1.5), (2, "", '\0', 2.5), (0, "", '\0', 3.5)];
+    let actual = zip_longest4(v1.into_iter(), v2.into_iter(), v3.into_iter(), v4.into_iter()).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(
+        zip_longest4(v1.into_iter(), v2.into_iter(), v3.into_iter(), v4.into_iter()).size_hint(),
+        (expected.len(), Some(expected.len()))
+    );
+}
+
+#[test]
+fn test_zip_longest_either() {
+    let first = "abc";
+    let second = [0, 1, 2, 3];
+    let expected = [
+        EitherOrBoth::Both('a', 0),
+        EitherOrBoth::Both('b', 1),
+        EitherOrBoth::Both('c', 2),
+        EitherOrBoth::Right(3),
+    ];
+    let actual = first.chars().zip_longest_either(second.into_iter()).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(first.chars().zip_longest_either(second.into_iter()).count(), expected.len());
+    assert_eq!(first.chars().zip_longest_either(second.into_iter()).size_hint(), (4, Some(expected.len())));
+}
+
+#[test]
+fn test_zip_longest_either_no_default() {
+    #[derive(Debug, PartialEq)]
+    struct Opaque(u32);
+
+    let first = [Opaque(1), Opaque(2)];
+    let second: [Opaque; 0] = [];
+    let expected = [EitherOrBoth::Left(Opaque(1)), EitherOrBoth::Left(Opaque(2))];
+    let actual = zip_longest_either(first.into_iter(), second.into_iter()).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_either_or_both() {
+    let both: EitherOrBoth<i32, i32> = EitherOrBoth::Both(1, 2);
+    let left: EitherOrBoth<i32, i32> = EitherOrBoth::Left(3);
+    let right: EitherOrBoth<i32, i32> = EitherOrBoth::Right(4);
+
+    assert_eq!(both.left(), Some(1));
+    assert_eq!(both.right(), Some(2));
+    assert_eq!(left.right(), None);
+    assert_eq!(right.left(), None);
+    assert!(both.is_both() && both.has_left() && both.has_right());
+    assert!(left.has_left() && !left.has_right());
+    assert!(!right.has_left() && right.has_right());
+    assert_eq!(both.both(), Some((1, 2)));
+    assert_eq!(left.both(), None);
+    assert_eq!(left.or(-1, -2), (3, -2));
+    assert_eq!(right.or(-1, -2), (-1, 4));
+    assert_eq!(right.or_else(|| -1, || -2), (-1, 4));
+    assert_eq!(left.or_default(), (3, 0));
+    assert_eq!(both.reduce(|a, b| a + b), 3);
+    assert_eq!(left.reduce(|a, b| a + b), 3);
+    assert_eq!(right.reduce(|a, b| a + b), 4);
+    assert_eq!(both.as_ref(), EitherOrBoth::Both(&1, &2));
+}
+
+#[test]
+fn test_zip_longest_fill() {
+    let first = [1.0, 2.0, 3.0];
+    let second = [10.0];
+    let actual = zip_longest_fill(first.into_iter(), second.into_iter(), f64::NAN, f64::NAN).collect::<Vec<_>>();
+
+    assert_eq!(actual.len(), 3);
+    assert_eq!(actual[0], (1.0, 10.0));
+    assert_eq!(actual[1].0, 2.0);
+    assert!(actual[1].1.is_nan());
+    assert_eq!(actual[2].0, 3.0);
+    assert!(actual[2].1.is_nan());
+    assert_eq!(zip_longest_fill(first.into_iter(), second.into_iter(), f64::NAN, f64::NAN).count(), 3);
+}
+
+#[test]
+fn test_zip_longest_fill_first() {
+    let first = "a";
+    let second = "012";
+    let expected = [('a', '0'), ('-', '1'), ('-', '2')];
+    let actual = first.chars().zip_longest_fill(second.chars(), '-', '+').collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_zip_longest_with() {
+    let first = "ab";
+    let second = [1, 2, 3, 4];
+    let mut counter = 0;
+    let expected = [("a".to_string(), 1), ("b".to_string(), 2), ("pad1".to_string(), 3), ("pad2".to_string(), 4)];
+    let actual = first.chars().map(String::from).zip_longest_with(
+        second.into_iter(),
+        || { counter += 1; format!("pad{counter}") },
+        || 0,
+    ).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(
+        zip_longest_with(second.into_iter(), first.chars(), || 0, || '?').collect::<Vec<_>>(),
+        [(1, 'a'), (2, 'b'), (3, '?'), (4, '?')]
+    );
+}
+
+// yields None every other call, which is allowed for non-fused iterators
+struct Flaky {
+    value: u32,
+}
+
+impl Iterator for Flaky {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.value += 1;
+        if self.value.is_multiple_of(2) {
+            None
+        } else {
+            Some(self.value)
+        }
+    }
+}
+
+#[test]
+fn test_zip_longest_not_fused() {
+    let first = Flaky { value: 0 };
+    let second = [1, 2, 3];
+    let expected = [(1, 1), (0, 2), (0, 3)];
+    let actual = first.zip_longest(second.into_iter()).collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+}
+
+#[test]
+fn test_zip_longest_not_polling_exhausted() {
+    let mut polled = 0;
+    let first = [1, 2].into_iter().inspect(|_| polled += 1);
+    let second = [1, 2, 3, 4, 5];
+    let mut iter = first.zip_longest(second.into_iter());
+
+    while iter.next().is_some() {}
+    assert_eq!(iter.next(), None);
+    drop(iter);
+
+    assert_eq!(polled, 2);
+}
+
+#[test]
+fn test_zip_longest_rev() {
+    let first = "abc";
+    let second = "01234";
+    let expected = [('\0', '4'), ('\0', '3'), ('c', '2'), ('b', '1'), ('a', '0')];
+    let first = first.chars().collect::<Vec<_>>();
+    let second = second.chars().collect::<Vec<_>>();
+    let actual = first.iter().copied().zip_longest(second.iter().copied()).rev().collect::<Vec<_>>();
+
+    assert_eq!(&expected[..], &actual[..]);
+    assert_eq!(first.iter().copied().zip_longest(second.iter().copied()).len(), expected.len());
+}
+
+#[test]
+fn test_zip_longest_both_ends() {
+    let first = [1, 2, 3, 4];
+    let second = [10, 20];
+    let mut iter = first.into_iter().zip_longest_either(second.into_iter());
+
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next_back(), Some(EitherOrBoth::Left(4)));
+    assert_eq!(iter.next(), Some(EitherOrBoth::Both(1, 10)));
+    assert_eq!(iter.next_back(), Some(EitherOrBoth::Left(3)));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(EitherOrBoth::Both(2, 20)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let actual = zip_longest_fill(second.into_iter(), first.into_iter(), -1, -1).rev().collect::<Vec<_>>();
+    assert_eq!(actual, [(-1, 4), (-1, 3), (20, 2), (10, 1)]);
+}